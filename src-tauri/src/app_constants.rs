@@ -0,0 +1,4 @@
+/// File name (under `<packaged root>/logs/`, see [`crate::logging::resolve_desktop_log_path`])
+/// that all `append_*_log` helpers in [`crate::app_helpers`] share, each
+/// tagging its own lines rather than writing to separate files.
+pub(crate) const DESKTOP_LOG_FILE: &str = "desktop.log";
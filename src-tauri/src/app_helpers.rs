@@ -0,0 +1,40 @@
+use std::{fs::OpenOptions, io::Write};
+
+use crate::{log_window, logging, runtime_paths, DESKTOP_LOG_FILE};
+
+/// Writes a `[tag] line` entry to the shared desktop log file and forwards
+/// it to [`log_window::broadcast_log_line`], so every `append_*_log` call
+/// site shows up in the live log-viewer window as well as on disk, the same
+/// way backend stdout/stderr lines already do.
+fn append_log_line(tag: &str, line: &str) {
+    let log_path = logging::resolve_desktop_log_path(
+        runtime_paths::default_packaged_root_dir(),
+        DESKTOP_LOG_FILE,
+    );
+
+    if let Some(parent) = log_path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&log_path) {
+        let _ = writeln!(file, "[{tag}] {line}");
+    }
+
+    log_window::broadcast_log_line(&format!("[{tag}] {line}"));
+}
+
+pub(crate) fn append_desktop_log(line: &str) {
+    append_log_line("desktop", line);
+}
+
+pub(crate) fn append_startup_log(line: &str) {
+    append_log_line("startup", line);
+}
+
+pub(crate) fn append_restart_log(line: &str) {
+    append_log_line("restart", line);
+}
+
+pub(crate) fn append_shutdown_log(line: &str) {
+    append_log_line("shutdown", line);
+}
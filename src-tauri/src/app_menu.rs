@@ -0,0 +1,172 @@
+use tauri::{
+    menu::{Menu, MenuItem, PredefinedMenuItem, Submenu},
+    AppHandle, Manager,
+};
+
+use crate::{keybindings, runtime_paths, shell_locale, tray_actions, DEFAULT_SHELL_LOCALE};
+
+fn menu_item(
+    app_handle: &AppHandle,
+    keymap: &keybindings::Keymap,
+    id: &'static str,
+    label: &str,
+    enabled: bool,
+) -> Result<MenuItem<tauri::Wry>, String> {
+    MenuItem::with_id(app_handle, id, label, enabled, keymap.accelerator_for(id))
+        .map_err(|error| format!("Failed to create app menu item {id}: {error}"))
+}
+
+/// Builds the native window menu bar (File/View/Backend/Help, plus a macOS
+/// App submenu) and attaches it to the app. Shares tray action IDs with
+/// `tray_setup` so clicks route through the same `tray_menu_handler`.
+pub fn setup_app_menu(app_handle: &AppHandle) -> Result<(), String> {
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    let locale =
+        shell_locale::resolve_shell_locale(DEFAULT_SHELL_LOCALE, packaged_root_dir.clone());
+    let shell_texts = shell_locale::shell_texts_for_locale(&locale, packaged_root_dir.as_deref());
+    let keymap = keybindings::resolve_keymap(packaged_root_dir.as_deref());
+
+    let toggle_item = menu_item(
+        app_handle,
+        &keymap,
+        tray_actions::TRAY_MENU_TOGGLE_WINDOW,
+        &shell_texts.tray_hide,
+        true,
+    )?;
+    let reload_item = menu_item(
+        app_handle,
+        &keymap,
+        tray_actions::TRAY_MENU_RELOAD_WINDOW,
+        &shell_texts.tray_reload,
+        true,
+    )?;
+    let restart_backend_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_RESTART_BACKEND_CONFIRM,
+        &shell_texts.tray_restart_backend,
+        true,
+        keymap.accelerator_for(tray_actions::TRAY_MENU_RESTART_BACKEND),
+    )
+    .map_err(|error| format!("Failed to create app menu restart backend item: {error}"))?;
+    let check_update_item = menu_item(
+        app_handle,
+        &keymap,
+        tray_actions::TRAY_MENU_CHECK_UPDATE,
+        &shell_texts.tray_check_update,
+        true,
+    )?;
+    let cancel_update_item = menu_item(
+        app_handle,
+        &keymap,
+        tray_actions::TRAY_MENU_CANCEL_UPDATE,
+        &shell_texts.tray_cancel_update,
+        false,
+    )?;
+    let update_channel_item = menu_item(
+        app_handle,
+        &keymap,
+        tray_actions::TRAY_MENU_CYCLE_UPDATE_CHANNEL,
+        &shell_texts.tray_update_channel_stable,
+        true,
+    )?;
+    let check_external_update_item = menu_item(
+        app_handle,
+        &keymap,
+        tray_actions::TRAY_MENU_CHECK_EXTERNAL_UPDATE,
+        &shell_texts.tray_check_external_update,
+        true,
+    )?;
+    let open_logs_item = menu_item(
+        app_handle,
+        &keymap,
+        tray_actions::TRAY_MENU_OPEN_LOGS,
+        &shell_texts.tray_open_logs,
+        true,
+    )?;
+    let quit_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_QUIT_CONFIRM,
+        &shell_texts.tray_quit,
+        true,
+        keymap.accelerator_for(tray_actions::TRAY_MENU_QUIT),
+    )
+    .map_err(|error| format!("Failed to create app menu quit item: {error}"))?;
+
+    let file_menu = Submenu::with_items(app_handle, "File", true, &[&open_logs_item, &quit_item])
+        .map_err(|error| format!("Failed to create File menu: {error}"))?;
+    let view_menu = Submenu::with_items(app_handle, "View", true, &[&toggle_item, &reload_item])
+        .map_err(|error| format!("Failed to create View menu: {error}"))?;
+    let backend_menu = Submenu::with_items(
+        app_handle,
+        "Backend",
+        true,
+        &[
+            &restart_backend_item,
+            &check_update_item,
+            &cancel_update_item,
+            &update_channel_item,
+            &check_external_update_item,
+        ],
+    )
+    .map_err(|error| format!("Failed to create Backend menu: {error}"))?;
+    let help_about_item = PredefinedMenuItem::about(app_handle, Some("About AstrBot"), None)
+        .map_err(|error| format!("Failed to create about menu item: {error}"))?;
+    let help_menu = Submenu::with_items(app_handle, "Help", true, &[&help_about_item])
+        .map_err(|error| format!("Failed to create Help menu: {error}"))?;
+
+    #[cfg(target_os = "macos")]
+    {
+        let about_item = PredefinedMenuItem::about(app_handle, None, None)
+            .map_err(|error| format!("Failed to create about menu item: {error}"))?;
+        let services_item = PredefinedMenuItem::services(app_handle, None)
+            .map_err(|error| format!("Failed to create services menu item: {error}"))?;
+        let hide_item = PredefinedMenuItem::hide(app_handle, None)
+            .map_err(|error| format!("Failed to create hide menu item: {error}"))?;
+        let separator = PredefinedMenuItem::separator(app_handle)
+            .map_err(|error| format!("Failed to create app menu separator: {error}"))?;
+        let quit_predefined_item = PredefinedMenuItem::quit(app_handle, None)
+            .map_err(|error| format!("Failed to create quit menu item: {error}"))?;
+
+        let app_menu = Submenu::with_items(
+            app_handle,
+            "AstrBot",
+            true,
+            &[
+                &about_item,
+                &separator,
+                &services_item,
+                &hide_item,
+                &separator,
+                &quit_predefined_item,
+            ],
+        )
+        .map_err(|error| format!("Failed to create App menu: {error}"))?;
+
+        let menu = Menu::with_items(
+            app_handle,
+            &[&app_menu, &file_menu, &view_menu, &backend_menu, &help_menu],
+        )
+        .map_err(|error| format!("Failed to build app menu: {error}"))?;
+
+        app_handle
+            .set_menu(menu)
+            .map_err(|error| format!("Failed to set app menu: {error}"))?;
+
+        return Ok(());
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let menu = Menu::with_items(
+            app_handle,
+            &[&file_menu, &view_menu, &backend_menu, &help_menu],
+        )
+        .map_err(|error| format!("Failed to build app menu: {error}"))?;
+
+        app_handle
+            .set_menu(menu)
+            .map_err(|error| format!("Failed to set app menu: {error}"))?;
+
+        Ok(())
+    }
+}
@@ -1,12 +1,15 @@
 use std::time::Instant;
+
 use tauri::{webview::PageLoadEvent, Manager, RunEvent, WindowEvent};
 use tauri_plugin_dialog::DialogExt;
-use tauri_plugin_updater::UpdaterExt;
 
 use crate::{
-    append_desktop_log, append_startup_log, desktop_bridge, exit_events, startup_loading,
-    startup_task, tray_setup, window_actions, AutoUpdateCheckState, BackendState,
-    DEFAULT_SHELL_LOCALE, DESKTOP_LOG_FILE, STARTUP_MODE_ENV,
+    app_menu, append_desktop_log, append_startup_log, desktop_bridge, exit_events, external_update,
+    log_window, staged_update, startup_loading, startup_task, tray_menu_handler, tray_setup,
+    update_channel,
+    update_download::{self, UpdateDownloadOutcome},
+    window_actions, AutoUpdateCheckState, BackendState, ManualUpdateCheckState, PendingUpdateState,
+    UpdateChannelState, UpdateState, DEFAULT_SHELL_LOCALE, DESKTOP_LOG_FILE, STARTUP_MODE_ENV,
 };
 
 pub(crate) fn run() {
@@ -29,18 +32,41 @@ pub(crate) fn run() {
         .plugin(tauri_plugin_dialog::init())
         .manage(BackendState::default())
         .manage(AutoUpdateCheckState::new(auto_update_check_enabled))
+        .manage(UpdateState::default())
+        .manage(ManualUpdateCheckState::default())
+        .manage(PendingUpdateState::default())
         .invoke_handler(tauri::generate_handler![
             crate::desktop_bridge_commands::desktop_bridge_is_desktop_runtime,
             crate::desktop_bridge_commands::desktop_bridge_get_backend_state,
+            crate::desktop_bridge_commands::desktop_bridge_subscribe_backend_state,
             crate::desktop_bridge_commands::desktop_bridge_set_auth_token,
             crate::desktop_bridge_commands::desktop_bridge_set_shell_locale,
+            crate::desktop_bridge_commands::desktop_bridge_list_shell_locales,
             crate::desktop_bridge_commands::desktop_bridge_restart_backend,
             crate::desktop_bridge_commands::desktop_bridge_stop_backend,
             crate::desktop_bridge_commands::desktop_bridge_open_external_url,
+            crate::desktop_bridge_commands::desktop_bridge_open_path,
+            crate::desktop_bridge_commands::desktop_bridge_reveal_in_file_manager,
+            crate::desktop_bridge_commands::desktop_bridge_set_update_channel,
             crate::desktop_bridge_commands::desktop_bridge_check_desktop_app_update,
             crate::desktop_bridge_commands::desktop_bridge_install_desktop_app_update,
+            crate::desktop_bridge_commands::desktop_bridge_download_desktop_app_update,
+            crate::desktop_bridge_commands::desktop_bridge_install_pending_update,
+            crate::desktop_bridge_commands::desktop_bridge_cancel_update,
+            crate::desktop_bridge_commands::desktop_bridge_apply_external_update,
         ])
+        .on_menu_event(|app, event| {
+            tray_menu_handler::handle_tray_menu_event(app, event.id().as_ref())
+        })
         .on_window_event(|window, event| {
+            if window.label() == log_window::LOG_WINDOW_LABEL {
+                if let WindowEvent::CloseRequested { api, .. } = event {
+                    api.prevent_close();
+                    window_actions::hide_log_window(window.app_handle(), append_desktop_log);
+                }
+                return;
+            }
+
             if window.label() != "main" {
                 return;
             }
@@ -104,16 +130,56 @@ pub(crate) fn run() {
         })
         .setup(move |app| {
             let app_handle = app.handle().clone();
+            log_window::register_log_broadcast_handle(&app_handle);
             if let Err(error) = tray_setup::setup_tray(&app_handle) {
                 append_startup_log(&format!("failed to initialize tray: {error}"));
             }
+            if let Err(error) = app_menu::setup_app_menu(&app_handle) {
+                append_startup_log(&format!("failed to initialize app menu: {error}"));
+            }
 
             startup_task::spawn_startup_task(app_handle.clone(), append_startup_log);
+            crate::backend_restart::spawn_crash_supervisor(app_handle.clone(), !cfg!(debug_assertions));
+            crate::backend_runtime::spawn_dev_watch_if_enabled(app_handle.clone());
 
             // 启动时静默检查更新；若发现新版本则弹窗询问是否立即下载并安装
             let startup_app_handle = app_handle.clone();
             tauri::async_runtime::spawn(async move {
                 let current_version = startup_app_handle.package_info().version.to_string();
+
+                let external_update_root_dir = crate::runtime_paths::default_packaged_root_dir();
+                if let Some(plan) = external_update::find_pending_external_update(
+                    external_update_root_dir.as_deref(),
+                    &current_version,
+                ) {
+                    append_startup_log(&format!(
+                        "[更新检查] 发现本地离线更新清单 {}，优先于远程检查",
+                        plan.version
+                    ));
+
+                    let dialog = startup_app_handle.dialog();
+                    let should_update = dialog
+                        .message(format!(
+                            "发现本地离线更新包 {}，是否立即安装？\n选择“否”可稍后通过托盘菜单安装。",
+                            plan.version
+                        ))
+                        .title("发现本地更新")
+                        .kind(tauri_plugin_dialog::MessageDialogKind::Info)
+                        .buttons(tauri_plugin_dialog::MessageDialogButtons::YesNo)
+                        .blocking_show();
+
+                    if should_update {
+                        if let Err(error) =
+                            external_update::install_external_update(&startup_app_handle, &plan)
+                        {
+                            append_startup_log(&format!("[更新检查] 安装本地更新失败：{error}"));
+                        }
+                    } else {
+                        append_startup_log("[更新检查] 用户选择稍后处理本地更新");
+                    }
+                    return;
+                }
+
                 let auto_update_enabled = startup_app_handle
                     .try_state::<AutoUpdateCheckState>()
                     .map(|state| state.is_enabled())
@@ -123,8 +189,14 @@ pub(crate) fn run() {
                     return;
                 }
 
+                let update_channel = startup_app_handle
+                    .try_state::<UpdateChannelState>()
+                    .map(|state| state.current())
+                    .unwrap_or_default();
+
                 append_startup_log("[更新检查] 正在初始化更新器...");
-                match startup_app_handle.updater() {
+                match update_channel::build_updater_for_channel(&startup_app_handle, update_channel)
+                {
                     Ok(updater) => {
                         append_startup_log(&format!(
                             "[更新检查] 更新器初始化成功，正在检查更新... current_version={}",
@@ -141,6 +213,16 @@ pub(crate) fn run() {
                                     check_started.elapsed().as_millis()
                                 ));
 
+                                let staged_version = staged_update::staged_update_version(
+                                    crate::runtime_paths::default_packaged_root_dir().as_deref(),
+                                );
+                                if staged_version.as_deref() == Some(new_version.as_str()) {
+                                    append_startup_log(&format!(
+                                        "[更新检查] 更新 {new_version} 已暂存，将在下次退出应用时安装，跳过重复下载"
+                                    ));
+                                    return;
+                                }
+
                                 let dialog = startup_app_handle.dialog();
                                 let should_update = dialog
                                     .message(format!(
@@ -162,10 +244,27 @@ pub(crate) fn run() {
                                 }
 
                                 append_startup_log("[更新检查] 用户确认更新，正在下载更新...");
-                                let downloaded_bytes = match update.download(|_, _| {}, || {}).await
-                                {
-                                    Ok(bytes) => bytes,
-                                    Err(error) => {
+                                let download_outcome = update_download::download_update_with_progress(
+                                    &startup_app_handle,
+                                    &update,
+                                )
+                                .await;
+
+                                let downloaded_bytes = match download_outcome {
+                                    UpdateDownloadOutcome::Downloaded(bytes) => bytes,
+                                    UpdateDownloadOutcome::AlreadyInProgress => {
+                                        append_startup_log(
+                                            "[更新检查] 已有更新下载在进行中，跳过本次下载",
+                                        );
+                                        return;
+                                    }
+                                    UpdateDownloadOutcome::Cancelled => {
+                                        append_startup_log(
+                                            "[更新检查] 用户取消了更新下载，已丢弃已下载的数据",
+                                        );
+                                        return;
+                                    }
+                                    UpdateDownloadOutcome::Failed(error) => {
                                         append_startup_log(&format!(
                                             "[更新检查] 下载更新失败：{error}"
                                         ));
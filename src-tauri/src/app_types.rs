@@ -18,6 +18,11 @@ pub(crate) struct TrayMenuState {
     pub(crate) reload_item: MenuItem<tauri::Wry>,
     pub(crate) restart_backend_item: MenuItem<tauri::Wry>,
     pub(crate) auto_update_check_item: MenuItem<tauri::Wry>,
+    pub(crate) check_update_item: MenuItem<tauri::Wry>,
+    pub(crate) cancel_update_item: MenuItem<tauri::Wry>,
+    pub(crate) update_channel_item: MenuItem<tauri::Wry>,
+    pub(crate) check_external_update_item: MenuItem<tauri::Wry>,
+    pub(crate) open_logs_item: MenuItem<tauri::Wry>,
     pub(crate) quit_item: MenuItem<tauri::Wry>,
 }
 
@@ -54,6 +59,173 @@ pub(crate) struct AutoUpdateCheckState {
     pub(crate) enabled: Mutex<bool>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum UpdateChannel {
+    Stable,
+    Beta,
+}
+
+impl UpdateChannel {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            UpdateChannel::Stable => "stable",
+            UpdateChannel::Beta => "beta",
+        }
+    }
+
+    pub(crate) fn from_str(raw: &str) -> Option<Self> {
+        match raw {
+            "stable" => Some(UpdateChannel::Stable),
+            "beta" => Some(UpdateChannel::Beta),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn next(self) -> Self {
+        match self {
+            UpdateChannel::Stable => UpdateChannel::Beta,
+            UpdateChannel::Beta => UpdateChannel::Stable,
+        }
+    }
+}
+
+impl Default for UpdateChannel {
+    fn default() -> Self {
+        UpdateChannel::Stable
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateChannelState {
+    pub(crate) channel: Mutex<UpdateChannel>,
+}
+
+impl UpdateChannelState {
+    pub(crate) fn new(channel: UpdateChannel) -> Self {
+        Self {
+            channel: Mutex::new(channel),
+        }
+    }
+
+    pub(crate) fn current(&self) -> UpdateChannel {
+        self.channel.lock().map(|guard| *guard).unwrap_or_default()
+    }
+
+    pub(crate) fn cycle(&self) -> UpdateChannel {
+        match self.channel.lock() {
+            Ok(mut guard) => {
+                *guard = guard.next();
+                *guard
+            }
+            Err(_) => UpdateChannel::default(),
+        }
+    }
+
+    pub(crate) fn set(&self, channel: UpdateChannel) {
+        if let Ok(mut guard) = self.channel.lock() {
+            *guard = channel;
+        }
+    }
+}
+
+/// Guards the manual "Check for updates" tray action against overlapping
+/// `updater().check()` tasks spawned by repeated clicks.
+#[derive(Debug, Default)]
+pub(crate) struct ManualUpdateCheckState {
+    pub(crate) in_progress: AtomicBool,
+}
+
+pub(crate) const UPDATE_DOWNLOAD_STARTED_EVENT: &str = "desktop-update://download-started";
+pub(crate) const UPDATE_DOWNLOAD_PROGRESS_EVENT: &str = "desktop-update://download-progress";
+pub(crate) const UPDATE_DOWNLOAD_FINISHED_EVENT: &str = "desktop-update://download-finished";
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UpdateDownloadStartedPayload {
+    pub(crate) total: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UpdateDownloadProgressPayload {
+    pub(crate) downloaded: u64,
+    pub(crate) total: Option<u64>,
+    pub(crate) percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UpdateDownloadFinishedPayload {
+    pub(crate) downloaded: u64,
+}
+
+/// Tracks the in-flight startup/manual update download so a tray "Cancel update"
+/// click can abort it before the full archive has been fetched.
+#[derive(Debug, Default)]
+pub(crate) struct UpdateState {
+    pub(crate) cancel_requested: AtomicBool,
+    pub(crate) download_in_progress: AtomicBool,
+}
+
+impl UpdateState {
+    pub(crate) fn begin_download(&self) -> bool {
+        self.cancel_requested.store(false, Ordering::Relaxed);
+        self.download_in_progress
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+    }
+
+    pub(crate) fn finish_download(&self) {
+        self.download_in_progress.store(false, Ordering::Relaxed);
+        self.cancel_requested.store(false, Ordering::Relaxed);
+    }
+
+    pub(crate) fn request_cancel(&self) -> bool {
+        if !self.download_in_progress.load(Ordering::Relaxed) {
+            return false;
+        }
+        self.cancel_requested.store(true, Ordering::Relaxed);
+        true
+    }
+
+    pub(crate) fn is_cancel_requested(&self) -> bool {
+        self.cancel_requested.load(Ordering::Relaxed)
+    }
+}
+
+/// Caches a desktop-app update archive downloaded via
+/// `desktop_bridge_download_desktop_app_update` until a later "install now"
+/// bridge command applies it, so the UI can offer its own install prompt
+/// instead of being forced through the blocking dialog the auto-update and
+/// one-shot install flows use.
+#[derive(Default)]
+pub(crate) struct PendingUpdateState {
+    pending: Mutex<Option<(String, Vec<u8>)>>,
+}
+
+impl PendingUpdateState {
+    pub(crate) fn store(&self, version: String, bytes: Vec<u8>) {
+        if let Ok(mut guard) = self.pending.lock() {
+            *guard = Some((version, bytes));
+        }
+    }
+
+    /// Returns the cached bytes only if they match `version`, so installing
+    /// a stale download (a newer update appeared since) fails closed rather
+    /// than silently applying the wrong archive.
+    pub(crate) fn take_for_version(&self, version: &str) -> Option<Vec<u8>> {
+        let mut guard = self.pending.lock().ok()?;
+        match guard.take() {
+            Some((pending_version, bytes)) if pending_version == version => Some(bytes),
+            other => {
+                *guard = other;
+                None
+            }
+        }
+    }
+}
+
 impl AutoUpdateCheckState {
     pub(crate) fn new(enabled: bool) -> Self {
         Self {
@@ -76,7 +248,7 @@ impl AutoUpdateCheckState {
     }
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct BackendBridgeState {
     pub(crate) running: bool,
@@ -99,6 +271,7 @@ pub(crate) struct DesktopAppUpdateCheckResult {
     pub(crate) current_version: String,
     pub(crate) latest_version: Option<String>,
     pub(crate) has_update: bool,
+    pub(crate) channel: UpdateChannel,
 }
 
 pub(crate) struct AtomicFlagGuard<'a> {
@@ -147,7 +320,7 @@ impl Default for BackendState {
 mod tests {
     use std::sync::atomic::{AtomicBool, Ordering};
 
-    use super::AtomicFlagGuard;
+    use super::{AtomicFlagGuard, UpdateState};
 
     #[test]
     fn atomic_flag_guard_set_resets_flag_on_drop() {
@@ -171,4 +344,28 @@ mod tests {
         assert!(!flag.load(Ordering::Relaxed));
         assert!(AtomicFlagGuard::try_set(&flag).is_some());
     }
+
+    #[test]
+    fn update_state_request_cancel_only_applies_during_a_download() {
+        let state = UpdateState::default();
+        assert!(!state.request_cancel());
+
+        assert!(state.begin_download());
+        assert!(state.request_cancel());
+        assert!(state.is_cancel_requested());
+
+        state.finish_download();
+        assert!(!state.is_cancel_requested());
+        assert!(!state.request_cancel());
+    }
+
+    #[test]
+    fn update_state_begin_download_rejects_concurrent_downloads() {
+        let state = UpdateState::default();
+        assert!(state.begin_download());
+        assert!(!state.begin_download());
+
+        state.finish_download();
+        assert!(state.begin_download());
+    }
 }
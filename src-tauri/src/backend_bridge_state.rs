@@ -0,0 +1,38 @@
+use std::sync::atomic::Ordering;
+
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::{BackendBridgeState, BackendState};
+
+/// Emitted whenever `BackendState` transitions (spawned, became ready,
+/// stopped/crashed, or restarted), carrying the same payload
+/// `desktop_bridge_get_backend_state` returns, so the frontend can react
+/// instantly instead of polling that command.
+pub(crate) const BACKEND_STATE_EVENT: &str = "desktop-bridge://backend-state";
+
+impl BackendState {
+    /// Snapshots the fields the frontend cares about into the serializable
+    /// bridge payload. `can_manage` is always `true` in this codebase: the
+    /// desktop app always owns the backend process it launched, there is no
+    /// "externally managed backend" mode at this layer.
+    pub(crate) fn bridge_state(&self) -> BackendBridgeState {
+        let running = matches!(self.child.lock(), Ok(guard) if guard.is_some());
+        BackendBridgeState {
+            running,
+            spawning: self.is_spawning.load(Ordering::Relaxed),
+            restarting: self.is_restarting.load(Ordering::Relaxed),
+            can_manage: true,
+        }
+    }
+}
+
+/// Emits the current `BackendBridgeState` on [`BACKEND_STATE_EVENT`]. Called
+/// both from backend lifecycle transition points and from
+/// `desktop_bridge_subscribe_backend_state` so a freshly subscribed
+/// frontend immediately syncs up instead of waiting for the next
+/// transition.
+pub(crate) fn emit_backend_state(app_handle: &AppHandle) {
+    let state = app_handle.state::<BackendState>();
+    let bridge_state = state.bridge_state();
+    let _ = app_handle.emit(BACKEND_STATE_EVENT, bridge_state);
+}
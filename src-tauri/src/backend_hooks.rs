@@ -0,0 +1,152 @@
+use std::{env, fs, process::Command};
+
+use crate::LaunchPlan;
+
+pub(crate) const BACKEND_BEFORE_HOOK_ENV: &str = "ASTRBOT_BACKEND_BEFORE";
+pub(crate) const BACKEND_AFTER_HOOK_ENV: &str = "ASTRBOT_BACKEND_AFTER";
+const BACKEND_ENV_ENV: &str = "ASTRBOT_BACKEND_ENV";
+
+/// Parses `KEY=VALUE` lines out of `.env`-style text, skipping blank lines
+/// and `#` comments.
+fn parse_env_lines(contents: &str) -> Vec<(String, String)> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            line.split_once('=')
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Resolves `ASTRBOT_BACKEND_ENV`: either a `@path/to/file` reference to a
+/// `.env`-style file, or an inline comma-separated `KEY=VALUE` list. Lets
+/// users configure API keys and feature flags without editing the launch
+/// plan itself.
+fn backend_extra_env_vars() -> Vec<(String, String)> {
+    let Ok(raw) = env::var(BACKEND_ENV_ENV) else {
+        return Vec::new();
+    };
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Vec::new();
+    }
+
+    if let Some(env_file_path) = raw.strip_prefix('@') {
+        return fs::read_to_string(env_file_path.trim())
+            .map(|contents| parse_env_lines(&contents))
+            .unwrap_or_default();
+    }
+
+    parse_env_lines(&raw.replace(',', "\n"))
+}
+
+/// Injects the same environment `start_backend_process` gives the backend
+/// itself (Python unbuffering/encoding, packaged dashboard bind address,
+/// `ASTRBOT_ROOT`/`ASTRBOT_WEBUI_DIR`) plus any `ASTRBOT_BACKEND_ENV`
+/// extras, so lifecycle hooks see the same world the backend does.
+pub(crate) fn apply_backend_env(command: &mut Command, plan: &LaunchPlan) {
+    command
+        .env("PYTHONUNBUFFERED", "1")
+        .env(
+            "PYTHONUTF8",
+            env::var("PYTHONUTF8").unwrap_or_else(|_| "1".to_string()),
+        )
+        .env(
+            "PYTHONIOENCODING",
+            env::var("PYTHONIOENCODING").unwrap_or_else(|_| "utf-8".to_string()),
+        );
+
+    if plan.packaged_mode {
+        command.env("ASTRBOT_ELECTRON_CLIENT", "1");
+        if env::var("DASHBOARD_HOST").is_err() && env::var("ASTRBOT_DASHBOARD_HOST").is_err() {
+            command.env("DASHBOARD_HOST", "127.0.0.1");
+        }
+        if env::var("DASHBOARD_PORT").is_err() && env::var("ASTRBOT_DASHBOARD_PORT").is_err() {
+            command.env("DASHBOARD_PORT", "6185");
+        }
+    }
+
+    if let Some(root_dir) = &plan.root_dir {
+        command.env("ASTRBOT_ROOT", root_dir);
+    }
+    if let Some(webui_dir) = &plan.webui_dir {
+        command.env("ASTRBOT_WEBUI_DIR", webui_dir);
+    }
+
+    for (key, value) in backend_extra_env_vars() {
+        command.env(key, value);
+    }
+}
+
+/// Runs a shell-split lifecycle hook (`ASTRBOT_BACKEND_BEFORE` before the
+/// backend spawns, `ASTRBOT_BACKEND_AFTER` once it's ready) synchronously
+/// with the same `cwd` and injected environment as the backend process
+/// itself, failing fast with its exit code and captured output on a
+/// non-zero exit. A no-op if the hook env var isn't set.
+pub(crate) fn run_backend_lifecycle_hook(
+    hook_env_var: &str,
+    plan: &LaunchPlan,
+) -> Result<(), String> {
+    let Ok(raw_command) = env::var(hook_env_var) else {
+        return Ok(());
+    };
+    let raw_command = raw_command.trim();
+    if raw_command.is_empty() {
+        return Ok(());
+    }
+
+    let mut pieces = shlex::split(raw_command)
+        .ok_or_else(|| format!("Invalid {hook_env_var}: {raw_command}"))?;
+    if pieces.is_empty() {
+        return Ok(());
+    }
+    let program = pieces.remove(0);
+
+    let mut command = Command::new(&program);
+    command.args(&pieces).current_dir(&plan.cwd);
+    apply_backend_env(&mut command, plan);
+
+    let output = command
+        .output()
+        .map_err(|error| format!("Failed to run {hook_env_var} ({raw_command}): {error}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{hook_env_var} ({raw_command}) exited with {}: {}{}",
+            output.status,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_env_lines;
+
+    #[test]
+    fn parse_env_lines_skips_blank_lines_and_comments() {
+        let contents = "# a comment\nFOO=bar\n\nBAZ=qux\n";
+        let parsed = parse_env_lines(contents);
+        assert_eq!(
+            parsed,
+            vec![
+                ("FOO".to_string(), "bar".to_string()),
+                ("BAZ".to_string(), "qux".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_env_lines_trims_keys_and_values() {
+        let parsed = parse_env_lines(" KEY = value with spaces ");
+        assert_eq!(
+            parsed,
+            vec![("KEY".to_string(), "value with spaces".to_string())]
+        );
+    }
+}
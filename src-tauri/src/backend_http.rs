@@ -0,0 +1,26 @@
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    time::Duration,
+};
+
+use crate::http_response::{self, HttpResponse};
+
+/// Issues a bare-bones `GET` over a fresh TCP connection and parses the
+/// response. There's no need for a full HTTP client here: this only ever
+/// talks to the backend's own health endpoint on localhost.
+pub(crate) fn get(host: &str, port: u16, path: &str, timeout: Duration) -> Option<HttpResponse> {
+    let mut stream = TcpStream::connect((host, port)).ok()?;
+    stream.set_read_timeout(Some(timeout)).ok()?;
+    stream.set_write_timeout(Some(timeout)).ok()?;
+
+    let request = format!(
+        "GET {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\nUser-Agent: AstrBot-Desktop\r\n\r\n"
+    );
+    stream.write_all(request.as_bytes()).ok()?;
+
+    let mut raw = Vec::new();
+    let _ = stream.read_to_end(&mut raw);
+
+    http_response::parse_http_response(&raw)
+}
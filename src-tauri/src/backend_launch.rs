@@ -0,0 +1,237 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Read, Write},
+    path::{Path, PathBuf},
+    process::{ChildStderr, ChildStdout, Command, Stdio},
+    thread::{self, JoinHandle},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::{
+    append_desktop_log, backend_bridge_state, backend_hooks, build_debug_command, BackendState,
+    LaunchPlan,
+};
+
+pub(crate) const BACKEND_LOG_EVENT: &str = "backend://log";
+pub(crate) const BACKEND_TERMINATED_EVENT: &str = "backend://terminated";
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BackendLogLine {
+    pub(crate) stream: &'static str,
+    pub(crate) line: String,
+    pub(crate) ts: u128,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BackendTerminatedPayload {
+    pub(crate) code: Option<i32>,
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or(0)
+}
+
+fn backend_log_path(root_dir: Option<&Path>) -> Option<PathBuf> {
+    root_dir.map(|root| root.join("logs").join("backend.log"))
+}
+
+fn open_backend_log_for_append(log_path: &Path) -> Option<File> {
+    match OpenOptions::new().create(true).append(true).open(log_path) {
+        Ok(file) => Some(file),
+        Err(error) => {
+            append_desktop_log(&format!(
+                "failed to open backend log {} for streaming: {}",
+                log_path.display(),
+                error
+            ));
+            None
+        }
+    }
+}
+
+/// Reads `reader` line-by-line, tees each line to `log_file` (when present)
+/// and emits it to the webview as a [`BackendLogLine`] so a live log window
+/// can show backend startup progress and crashes without anyone having to
+/// go hunting for `backend.log` on disk. Mirrors the `CommandEvent::Stdout`/
+/// `Stderr` split Tauri's own `Command` API exposes.
+fn spawn_stream_reader_thread<R>(
+    app_handle: AppHandle,
+    reader: R,
+    mut log_file: Option<File>,
+    stream: &'static str,
+) -> JoinHandle<()>
+where
+    R: Read + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        let mut raw_line = String::new();
+        loop {
+            raw_line.clear();
+            match reader.read_line(&mut raw_line) {
+                Ok(0) => break,
+                Ok(_) => {
+                    let line = raw_line.trim_end_matches(['\r', '\n']);
+                    if let Some(file) = log_file.as_mut() {
+                        let _ = writeln!(file, "{line}");
+                    }
+                    crate::log_window::broadcast_log_line(&format!("[backend:{stream}] {line}"));
+                    let _ = app_handle.emit(
+                        BACKEND_LOG_EVENT,
+                        BackendLogLine {
+                            stream,
+                            line: line.to_string(),
+                            ts: now_millis(),
+                        },
+                    );
+                }
+                Err(_) => break,
+            }
+        }
+    })
+}
+
+/// Waits for both stream readers to hit EOF, reaps the child's exit status,
+/// clears `BackendState::child` and emits `backend://terminated` so the UI
+/// can react to a crash the moment it happens instead of discovering it on
+/// the next readiness poll.
+fn spawn_termination_watcher(
+    app_handle: AppHandle,
+    stdout_reader: JoinHandle<()>,
+    stderr_reader: JoinHandle<()>,
+) {
+    thread::spawn(move || {
+        let _ = stdout_reader.join();
+        let _ = stderr_reader.join();
+
+        let state = app_handle.state::<BackendState>();
+        let exit_code = match state.child.lock() {
+            Ok(mut guard) => {
+                let code = guard.as_mut().and_then(|child| match child.wait() {
+                    Ok(status) => status.code(),
+                    Err(_) => None,
+                });
+                *guard = None;
+                code
+            }
+            Err(_) => None,
+        };
+
+        let _ = app_handle.emit(
+            BACKEND_TERMINATED_EVENT,
+            BackendTerminatedPayload { code: exit_code },
+        );
+        backend_bridge_state::emit_backend_state(&app_handle);
+    });
+}
+
+impl BackendState {
+    /// Spawns the backend process with piped stdio instead of redirecting
+    /// straight into `backend.log`, so the webview gets a live view of
+    /// startup output and crashes. Every line is still teed to the log file
+    /// on disk for postmortem debugging; the difference is that it's also
+    /// streamed to the frontend as it happens.
+    pub(crate) fn start_backend_process(
+        &self,
+        app_handle: &AppHandle,
+        plan: &LaunchPlan,
+    ) -> Result<(), String> {
+        if self
+            .child
+            .lock()
+            .map_err(|_| "Backend process lock poisoned.")?
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        if !plan.cwd.exists() {
+            fs::create_dir_all(&plan.cwd).map_err(|error| {
+                format!(
+                    "Failed to create backend cwd {}: {}",
+                    plan.cwd.display(),
+                    error
+                )
+            })?;
+        }
+        if let Some(root_dir) = &plan.root_dir {
+            if !root_dir.exists() {
+                fs::create_dir_all(root_dir).map_err(|error| {
+                    format!(
+                        "Failed to create backend root directory {}: {}",
+                        root_dir.display(),
+                        error
+                    )
+                })?;
+            }
+        }
+
+        backend_hooks::run_backend_lifecycle_hook(backend_hooks::BACKEND_BEFORE_HOOK_ENV, plan)?;
+
+        let mut command = Command::new(&plan.cmd);
+        command
+            .args(&plan.args)
+            .current_dir(&plan.cwd)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        backend_hooks::apply_backend_env(&mut command, plan);
+
+        let log_path = backend_log_path(plan.root_dir.as_deref());
+        if let Some(log_path) = &log_path {
+            if let Some(log_parent) = log_path.parent() {
+                fs::create_dir_all(log_parent).map_err(|error| {
+                    format!(
+                        "Failed to create backend log directory {}: {}",
+                        log_parent.display(),
+                        error
+                    )
+                })?;
+            }
+        }
+
+        let mut child = command.spawn().map_err(|error| {
+            format!(
+                "Failed to spawn backend process with command {:?}: {}",
+                build_debug_command(plan),
+                error
+            )
+        })?;
+
+        let stdout: Option<ChildStdout> = child.stdout.take();
+        let stderr: Option<ChildStderr> = child.stderr.take();
+
+        let stdout_log_file = log_path.as_deref().and_then(open_backend_log_for_append);
+        let stderr_log_file = log_path.as_deref().and_then(open_backend_log_for_append);
+
+        let stdout_reader = spawn_stream_reader_thread(
+            app_handle.clone(),
+            stdout.ok_or_else(|| "Backend process stdout was not piped.".to_string())?,
+            stdout_log_file,
+            "stdout",
+        );
+        let stderr_reader = spawn_stream_reader_thread(
+            app_handle.clone(),
+            stderr.ok_or_else(|| "Backend process stderr was not piped.".to_string())?,
+            stderr_log_file,
+            "stderr",
+        );
+
+        *self
+            .child
+            .lock()
+            .map_err(|_| "Backend process lock poisoned.")? = Some(child);
+
+        spawn_termination_watcher(app_handle.clone(), stdout_reader, stderr_reader);
+        backend_bridge_state::emit_backend_state(app_handle);
+
+        Ok(())
+    }
+}
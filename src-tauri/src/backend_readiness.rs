@@ -0,0 +1,193 @@
+use std::{
+    env,
+    net::{TcpStream, ToSocketAddrs},
+    thread,
+    time::{Duration, Instant},
+};
+
+use url::Url;
+
+use crate::{backend_hooks, backend_http, BackendState, LaunchPlan};
+
+pub(crate) const BACKEND_HEALTH_PATH_ENV: &str = "ASTRBOT_BACKEND_HEALTH_PATH";
+pub(crate) const BACKEND_HEALTH_EXPECT_ENV: &str = "ASTRBOT_BACKEND_HEALTH_EXPECT";
+const DEFAULT_HEALTH_PATH: &str = "/api/stat/version";
+const HTTP_PROBE_TIMEOUT: Duration = Duration::from_millis(1500);
+const PACKAGED_BACKEND_TIMEOUT_FALLBACK_MS: u64 = 5 * 60 * 1000;
+
+fn health_path() -> String {
+    env::var(BACKEND_HEALTH_PATH_ENV)
+        .ok()
+        .map(|raw| raw.trim().to_string())
+        .filter(|raw| !raw.is_empty())
+        .unwrap_or_else(|| DEFAULT_HEALTH_PATH.to_string())
+}
+
+fn health_expect() -> Option<String> {
+    env::var(BACKEND_HEALTH_EXPECT_ENV)
+        .ok()
+        .map(|raw| raw.trim().to_string())
+        .filter(|raw| !raw.is_empty())
+}
+
+/// Mirrors `resolve_backend_timeout_ms` from the legacy launcher: packaged
+/// builds give up after 5 minutes by default (also when the override is 0),
+/// dev builds give up after 20s by default and wait indefinitely when the
+/// override is 0. Overridable via `ASTRBOT_BACKEND_TIMEOUT_MS`.
+fn resolve_backend_timeout_ms(packaged_mode: bool) -> Option<Duration> {
+    let default_timeout_ms = if packaged_mode { 0_u64 } else { 20_000_u64 };
+    let parsed_timeout_ms = env::var("ASTRBOT_BACKEND_TIMEOUT_MS")
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(default_timeout_ms);
+
+    if parsed_timeout_ms > 0 {
+        return Some(Duration::from_millis(parsed_timeout_ms));
+    }
+    if packaged_mode {
+        return Some(Duration::from_millis(PACKAGED_BACKEND_TIMEOUT_FALLBACK_MS));
+    }
+    None
+}
+
+/// Cheap pre-filter: is anything listening on the backend's host:port yet?
+fn tcp_port_open(backend_url: &str, timeout_ms: u64) -> bool {
+    let Ok(parsed) = Url::parse(backend_url) else {
+        return false;
+    };
+    let Some(host) = parsed.host_str() else {
+        return false;
+    };
+    let port = parsed.port_or_known_default().unwrap_or(80);
+    let timeout = Duration::from_millis(timeout_ms.max(50));
+
+    let addrs = match (host, port).to_socket_addrs() {
+        Ok(addrs) => addrs.collect::<Vec<_>>(),
+        Err(_) => return false,
+    };
+    addrs
+        .iter()
+        .any(|address| TcpStream::connect_timeout(address, timeout).is_ok())
+}
+
+/// Only once the socket is open: issue a `GET` to the configured health path
+/// and require a non-5xx status, plus (when `ASTRBOT_BACKEND_HEALTH_EXPECT`
+/// is set) that the body contains the expected substring/JSON key. This is
+/// what actually distinguishes "port open" from "dashboard serving".
+fn http_backend_ready(backend_url: &str) -> bool {
+    let Ok(parsed) = Url::parse(backend_url) else {
+        return false;
+    };
+    let Some(host) = parsed.host_str() else {
+        return false;
+    };
+    let port = parsed.port_or_known_default().unwrap_or(80);
+
+    let Some(response) = backend_http::get(host, port, &health_path(), HTTP_PROBE_TIMEOUT) else {
+        return false;
+    };
+
+    if response.is_server_error() {
+        return false;
+    }
+
+    match health_expect() {
+        Some(expected) => response.body.contains(&expected),
+        None => true,
+    }
+}
+
+impl BackendState {
+    /// Blocks until the backend is actually ready to serve the dashboard
+    /// (not merely listening), or the configured timeout elapses. Bails out
+    /// early if the child process exits before becoming reachable.
+    ///
+    /// Note: this doesn't emit `backend_bridge_state::BACKEND_STATE_EVENT`
+    /// itself, since it has no `AppHandle` to emit through; callers that
+    /// drive a launch to readiness are expected to call
+    /// `backend_bridge_state::emit_backend_state` once this returns `Ok`.
+    pub(crate) fn wait_for_backend(&self, plan: &LaunchPlan) -> Result<(), String> {
+        let timeout_ms = resolve_backend_timeout_ms(plan.packaged_mode);
+        let start_time = Instant::now();
+
+        loop {
+            if self.ping_backend(800) {
+                backend_hooks::run_backend_lifecycle_hook(
+                    backend_hooks::BACKEND_AFTER_HOOK_ENV,
+                    plan,
+                )?;
+                return Ok(());
+            }
+
+            {
+                let mut guard = self
+                    .child
+                    .lock()
+                    .map_err(|_| "Backend process lock poisoned.".to_string())?;
+                if let Some(child) = guard.as_mut() {
+                    match child.try_wait() {
+                        Ok(Some(status)) => {
+                            *guard = None;
+                            return Err(format!(
+                                "Backend process exited before becoming reachable: {status}"
+                            ));
+                        }
+                        Ok(None) => {}
+                        Err(error) => {
+                            return Err(format!("Failed to poll backend process status: {error}"));
+                        }
+                    }
+                } else {
+                    return Err("Backend process is not running.".to_string());
+                }
+            }
+
+            if let Some(limit) = timeout_ms {
+                if start_time.elapsed() >= limit {
+                    return Err(format!(
+                        "Timed out after {}ms waiting for backend startup.",
+                        limit.as_millis()
+                    ));
+                }
+            }
+
+            thread::sleep(Duration::from_millis(600));
+        }
+    }
+
+    /// Cheap TCP pre-filter, then (only once the socket is open) the HTTP
+    /// readiness probe, so the webview isn't navigated to a backend that's
+    /// accepted a connection but hasn't finished booting its dashboard.
+    fn ping_backend(&self, timeout_ms: u64) -> bool {
+        tcp_port_open(&self.backend_url, timeout_ms) && http_backend_ready(&self.backend_url)
+    }
+
+    /// Same probe [`Self::wait_for_backend`] uses internally, exposed for
+    /// callers (e.g. the crash supervisor) that need a one-off readiness
+    /// check without driving a full launch.
+    pub(crate) fn is_backend_ready(&self) -> bool {
+        self.ping_backend(800)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_backend_timeout_ms;
+    use std::time::Duration;
+
+    #[test]
+    fn dev_mode_defaults_to_a_20s_timeout() {
+        assert_eq!(
+            resolve_backend_timeout_ms(false),
+            Some(Duration::from_millis(20_000))
+        );
+    }
+
+    #[test]
+    fn packaged_mode_defaults_to_waiting_five_minutes() {
+        assert_eq!(
+            resolve_backend_timeout_ms(true),
+            Some(Duration::from_millis(5 * 60 * 1000))
+        );
+    }
+}
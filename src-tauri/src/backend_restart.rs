@@ -0,0 +1,195 @@
+use std::{
+    env, thread,
+    time::{Duration, Instant},
+};
+
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    append_desktop_log, append_restart_log, backend_bridge_state, restart_backend_flow,
+    ui_dispatch, window_actions, BackendState,
+};
+
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_millis(600);
+const INITIAL_RESTART_DELAY: Duration = Duration::from_secs(1);
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(60);
+const STABLE_UPTIME_THRESHOLD: Duration = Duration::from_secs(30);
+const MAX_CONSECUTIVE_FAILURES: u32 = 6;
+
+pub(crate) const BACKEND_SUPERVISE_ENV: &str = "ASTRBOT_BACKEND_SUPERVISE";
+
+/// Whether the crash supervisor should run at all. Packaged builds supervise
+/// by default since there's no terminal around to notice a silent crash;
+/// set `ASTRBOT_BACKEND_SUPERVISE=0` to disable it (e.g. while attaching a
+/// debugger to the backend process) or `=1` to force it on in dev builds.
+fn supervision_enabled(packaged_mode: bool) -> bool {
+    match env::var(BACKEND_SUPERVISE_ENV) {
+        Ok(raw) => !matches!(raw.as_str(), "0" | "false"),
+        Err(_) => packaged_mode,
+    }
+}
+
+/// Computes the backoff delay ahead of the Nth consecutive crash (1-indexed):
+/// 1s, 2s, 4s, 8s, ... capped at [`MAX_RESTART_DELAY`].
+fn restart_delay_for(consecutive_failures: u32) -> Duration {
+    let exponent = consecutive_failures.saturating_sub(1).min(6);
+    (INITIAL_RESTART_DELAY * 2u32.pow(exponent)).min(MAX_RESTART_DELAY)
+}
+
+/// Blocks the calling thread until the managed child exits on its own.
+/// Returns `false` if the state lock is gone, which only happens while the
+/// app is tearing down, so the supervisor should stop rather than restart.
+fn wait_for_unexpected_exit(app_handle: &AppHandle) -> bool {
+    loop {
+        thread::sleep(SUPERVISOR_POLL_INTERVAL);
+
+        let state = app_handle.state::<BackendState>();
+        let mut guard = match state.child.lock() {
+            Ok(guard) => guard,
+            Err(_) => return false,
+        };
+
+        match guard.as_mut() {
+            None => continue,
+            Some(child) => match child.try_wait() {
+                Ok(Some(_status)) => {
+                    *guard = None;
+                    return true;
+                }
+                Ok(None) => continue,
+                Err(_) => continue,
+            },
+        }
+    }
+}
+
+/// Waits up to [`STABLE_UPTIME_THRESHOLD`] to confirm a freshly restarted
+/// backend stays up, so a slow-but-fine startup isn't mistaken for another
+/// crash. Returns `false` early if the child dies again during the window.
+fn survived_stable_uptime_window(app_handle: &AppHandle) -> bool {
+    let started_at = Instant::now();
+    while started_at.elapsed() < STABLE_UPTIME_THRESHOLD {
+        thread::sleep(SUPERVISOR_POLL_INTERVAL);
+        let state = app_handle.state::<BackendState>();
+        let still_running = matches!(state.child.lock(), Ok(guard) if guard.is_some());
+        if !still_running {
+            return false;
+        }
+    }
+    true
+}
+
+/// Re-resolves the launch plan and respawns the backend via the same flow
+/// the tray menu's "Restart backend" action uses, then reloads the main
+/// window once the new process is ready.
+fn attempt_supervised_restart(app_handle: &AppHandle) -> bool {
+    let state = app_handle.state::<BackendState>();
+    if restart_backend_flow::is_backend_action_in_progress(&state) {
+        append_restart_log(
+            "crash supervisor skipped a restart: a manual backend action is already in progress",
+        );
+        return false;
+    }
+
+    let result = tauri::async_runtime::block_on(restart_backend_flow::run_restart_backend_task(
+        app_handle.clone(),
+        None,
+    ));
+    backend_bridge_state::emit_backend_state(app_handle);
+
+    if !result.ok {
+        let reason = result.reason.unwrap_or_else(|| "unknown error".to_string());
+        append_restart_log(&format!("crash supervisor restart failed: {reason}"));
+        return false;
+    }
+
+    append_restart_log("crash supervisor restarted the backend");
+    if let Err(error) = ui_dispatch::run_on_main_thread_dispatch(
+        app_handle,
+        "reload main window after supervised backend restart",
+        move |main_app| {
+            window_actions::reload_main_window(main_app, append_desktop_log);
+        },
+    ) {
+        append_restart_log(&format!(
+            "failed to schedule main window reload after supervised restart: {error}"
+        ));
+    }
+
+    true
+}
+
+/// Blocks until either the initial backend launch becomes ready (per
+/// [`BackendState::is_backend_ready`]) or its child exits first. Run before
+/// the supervisor starts treating exits as crashes, so a real startup
+/// failure (bad config, missing dependency) is surfaced once by the
+/// startup flow's own error handling instead of racing it into a silent,
+/// delayed auto-restart loop.
+fn wait_for_initial_readiness(app_handle: &AppHandle) -> bool {
+    loop {
+        let state = app_handle.state::<BackendState>();
+        if state.is_backend_ready() {
+            return true;
+        }
+
+        let still_running = matches!(state.child.lock(), Ok(guard) if guard.is_some());
+        if !still_running {
+            return false;
+        }
+
+        thread::sleep(SUPERVISOR_POLL_INTERVAL);
+    }
+}
+
+fn run_supervisor_loop(app_handle: AppHandle) {
+    if !wait_for_initial_readiness(&app_handle) {
+        append_restart_log(
+            "crash supervisor stood down: initial backend launch exited before becoming ready",
+        );
+        return;
+    }
+
+    let mut consecutive_failures: u32 = 0;
+
+    loop {
+        if !wait_for_unexpected_exit(&app_handle) {
+            return;
+        }
+
+        consecutive_failures += 1;
+        append_restart_log(&format!(
+            "backend process exited unexpectedly ({consecutive_failures} consecutive crash(es))"
+        ));
+
+        if consecutive_failures > MAX_CONSECUTIVE_FAILURES {
+            crate::startup_loading::show_startup_error(
+                &app_handle,
+                "The AstrBot backend keeps crashing and the desktop app has given up trying to \
+                 restart it automatically. Please check the logs.",
+            );
+            return;
+        }
+
+        thread::sleep(restart_delay_for(consecutive_failures));
+
+        if attempt_supervised_restart(&app_handle) && survived_stable_uptime_window(&app_handle) {
+            consecutive_failures = 0;
+        }
+    }
+}
+
+/// Spawns a background thread that watches the managed backend child for an
+/// unexpected exit and auto-restarts it with exponential backoff, mirroring
+/// how long-lived dev supervisors keep a `SharedChild` alive. Gated behind
+/// [`BACKEND_SUPERVISE_ENV`] (on by default in packaged mode). The thread
+/// itself waits for the initial launch to become ready before it starts
+/// watching for crashes (see [`wait_for_initial_readiness`]), so it never
+/// races the startup flow's own handling of an initial launch failure.
+pub(crate) fn spawn_crash_supervisor(app_handle: AppHandle, packaged_mode: bool) {
+    if !supervision_enabled(packaged_mode) {
+        append_restart_log("backend crash supervisor disabled (ASTRBOT_BACKEND_SUPERVISE)");
+        return;
+    }
+
+    thread::spawn(move || run_supervisor_loop(app_handle));
+}
@@ -0,0 +1,235 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    append_desktop_log, append_restart_log, restart_backend_flow, ui_dispatch, window_actions,
+    BackendState,
+};
+
+pub(crate) const BACKEND_WATCH_ENV: &str = "ASTRBOT_BACKEND_WATCH";
+pub(crate) const BACKEND_WATCH_PATHS_ENV: &str = "ASTRBOT_BACKEND_WATCH_PATHS";
+pub(crate) const BACKEND_WATCH_IGNORE_ENV: &str = "ASTRBOT_BACKEND_WATCH_IGNORE";
+
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(500);
+const DEFAULT_IGNORED_SEGMENTS: &[&[&str]] = &[
+    &["logs"],
+    &["dashboard", "dist"],
+    &[".git"],
+    &["__pycache__"],
+];
+
+fn watch_mode_enabled() -> bool {
+    matches!(env::var(BACKEND_WATCH_ENV).as_deref(), Ok("1") | Ok("true"))
+}
+
+fn watch_ignore_globs() -> Vec<String> {
+    env::var(BACKEND_WATCH_IGNORE_ENV)
+        .ok()
+        .map(|raw| {
+            raw.split(',')
+                .map(|piece| piece.trim().to_string())
+                .filter(|piece| !piece.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn watch_paths_override() -> Option<Vec<PathBuf>> {
+    let raw = env::var(BACKEND_WATCH_PATHS_ENV).ok()?;
+    let paths: Vec<PathBuf> = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|piece| !piece.is_empty())
+        .map(PathBuf::from)
+        .collect();
+
+    if paths.is_empty() {
+        None
+    } else {
+        Some(paths)
+    }
+}
+
+/// Minimal `*`-only glob matcher, sufficient for the simple `plugins/*` /
+/// `*.pyc`-style patterns `ASTRBOT_BACKEND_WATCH_IGNORE` is meant to carry.
+fn glob_matches(glob: &str, candidate: &str) -> bool {
+    match glob.split_once('*') {
+        None => candidate.contains(glob),
+        Some((prefix, suffix)) => candidate.contains(prefix) && candidate.ends_with(suffix),
+    }
+}
+
+/// Whether `path` falls under a directory the watcher should never restart
+/// for (build output, VCS metadata, caches) or matches one of the
+/// user-supplied `ASTRBOT_BACKEND_WATCH_IGNORE` globs.
+fn is_ignored_path(path: &Path, extra_ignored_globs: &[String]) -> bool {
+    let components: Vec<_> = path.components().map(|c| c.as_os_str()).collect();
+
+    let under_ignored_segment = DEFAULT_IGNORED_SEGMENTS.iter().any(|segment| {
+        components
+            .windows(segment.len())
+            .any(|window| window.iter().zip(*segment).all(|(a, b)| *a == *b))
+    });
+    if under_ignored_segment {
+        return true;
+    }
+
+    let path_str = path.to_string_lossy();
+    extra_ignored_globs
+        .iter()
+        .any(|glob| glob_matches(glob, &path_str))
+}
+
+fn restart_backend_for_watch(app_handle: &AppHandle) {
+    append_restart_log("dev backend watcher detected a source change, restarting the backend");
+
+    let state = app_handle.state::<BackendState>();
+    if restart_backend_flow::is_backend_action_in_progress(&state) {
+        append_restart_log(
+            "dev backend watcher skipped a restart: a backend action is already in progress",
+        );
+        return;
+    }
+
+    let result = tauri::async_runtime::block_on(restart_backend_flow::run_restart_backend_task(
+        app_handle.clone(),
+        None,
+    ));
+
+    if !result.ok {
+        let reason = result.reason.unwrap_or_else(|| "unknown error".to_string());
+        append_restart_log(&format!("dev backend watcher restart failed: {reason}"));
+        return;
+    }
+
+    if let Err(error) = ui_dispatch::run_on_main_thread_dispatch(
+        app_handle,
+        "reload main window after dev backend watch restart",
+        move |main_app| {
+            window_actions::reload_main_window(main_app, append_desktop_log);
+        },
+    ) {
+        append_restart_log(&format!(
+            "failed to schedule main window reload after dev watch restart: {error}"
+        ));
+    }
+}
+
+fn run_watch_loop(app_handle: AppHandle, watch_roots: Vec<PathBuf>, ignore_globs: Vec<String>) {
+    let (event_tx, event_rx) = channel::<notify::Result<Event>>();
+    let mut watcher = match RecommendedWatcher::new(
+        move |event| {
+            let _ = event_tx.send(event);
+        },
+        notify::Config::default(),
+    ) {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            append_restart_log(&format!(
+                "failed to create dev backend file watcher: {error}"
+            ));
+            return;
+        }
+    };
+
+    for root in &watch_roots {
+        if let Err(error) = watcher.watch(root, RecursiveMode::Recursive) {
+            append_restart_log(&format!(
+                "failed to watch {} for dev backend hot-reload: {error}",
+                root.display()
+            ));
+        }
+    }
+
+    append_restart_log(&format!(
+        "dev backend hot-reload is watching {} path(s) for changes",
+        watch_roots.len()
+    ));
+
+    loop {
+        let Ok(event) = event_rx.recv() else {
+            return;
+        };
+        let Ok(event) = event else { continue };
+
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            continue;
+        }
+        if event
+            .paths
+            .iter()
+            .all(|path| is_ignored_path(path, &ignore_globs))
+        {
+            continue;
+        }
+
+        // Debounce: drain further events that land inside the window so a
+        // save-all in the editor triggers one restart instead of a dozen,
+        // extending the window each time a non-ignored event arrives.
+        // Ignored paths (e.g. the backend's own `logs/` directory, which a
+        // running backend writes to continuously) must not extend the
+        // window, or a real source change never gets past it.
+        let mut debounce_deadline = Instant::now() + DEBOUNCE_WINDOW;
+        loop {
+            let Some(remaining) = debounce_deadline.checked_duration_since(Instant::now()) else {
+                break;
+            };
+            match event_rx.recv_timeout(remaining) {
+                Ok(Ok(event))
+                    if event
+                        .paths
+                        .iter()
+                        .all(|path| is_ignored_path(path, &ignore_globs)) =>
+                {
+                    continue;
+                }
+                Ok(_) => {
+                    debounce_deadline = Instant::now() + DEBOUNCE_WINDOW;
+                    continue;
+                }
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+
+        restart_backend_for_watch(&app_handle);
+    }
+}
+
+/// Spawns the dev hot-reload watcher when `ASTRBOT_BACKEND_WATCH=1`. Watches
+/// `ASTRBOT_BACKEND_WATCH_PATHS` when set, otherwise the resolved AstrBot
+/// source root, and restarts the backend on change via the same flow the
+/// tray menu's "Restart backend" action uses. Mirrors the `--watch` restart
+/// loops CLI dev commands build on top of a filesystem watcher.
+pub(crate) fn spawn_dev_watch_if_enabled(app_handle: AppHandle) {
+    if !watch_mode_enabled() {
+        return;
+    }
+
+    let watch_roots = match watch_paths_override() {
+        Some(paths) => paths,
+        None => match crate::launch_plan::detect_astrbot_source_root() {
+            Some(source_root) => vec![source_root],
+            None => {
+                append_restart_log(
+                    "dev backend watcher is enabled but could not locate the AstrBot source root to watch",
+                );
+                return;
+            }
+        },
+    };
+
+    let ignore_globs = watch_ignore_globs();
+    thread::spawn(move || run_watch_loop(app_handle, watch_roots, ignore_globs));
+}
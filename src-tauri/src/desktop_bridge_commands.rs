@@ -1,13 +1,19 @@
-use std::process::{Command, Stdio};
+use std::{
+    collections::HashSet,
+    env,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
 use tauri::{AppHandle, Manager};
-use tauri_plugin_updater::UpdaterExt;
 use url::Url;
 
 use crate::{
-    append_desktop_log, restart_backend_flow, runtime_paths, shell_locale, tray_labels,
-    BackendBridgeResult, BackendBridgeState, BackendState, DesktopAppUpdateCheckResult,
-    DEFAULT_SHELL_LOCALE,
+    append_desktop_log, backend_bridge_state, external_update, restart_backend_flow, runtime_paths,
+    shell_locale, staged_update, tray_labels, update_channel, update_download, BackendBridgeResult,
+    BackendBridgeState, BackendState, DesktopAppUpdateCheckResult, PendingUpdateState,
+    UpdateChannel, UpdateChannelState, UpdateState, DEFAULT_SHELL_LOCALE,
 };
+use update_download::UpdateDownloadOutcome;
 
 fn parse_openable_url(raw_url: &str) -> Result<Url, String> {
     let trimmed = raw_url.trim();
@@ -24,9 +30,106 @@ fn parse_openable_url(raw_url: &str) -> Result<Url, String> {
     }
 }
 
+/// `PATH`-like variables a packaging runtime (AppImage/Flatpak/Snap) is
+/// known to inject bundle paths into ahead of launching us.
+const BUNDLE_SENSITIVE_ENV_VARS: &[&str] = &[
+    "PATH",
+    "LD_LIBRARY_PATH",
+    "XDG_DATA_DIRS",
+    "XDG_CONFIG_DIRS",
+    "GST_PLUGIN_PATH",
+    "GST_PLUGIN_SYSTEM_PATH",
+    "GTK_PATH",
+    "GIO_EXTRA_MODULES",
+    "GDK_PIXBUF_MODULE_FILE",
+    "QT_PLUGIN_PATH",
+    "QT_QPA_PLATFORM_PLUGIN_PATH",
+];
+
+/// Detects the bundle root we're running from, if any: `APPDIR` for
+/// AppImage (confirmed via the companion `APPIMAGE` var), `/app` for
+/// Flatpak (conventional install prefix, confirmed via `/.flatpak-info`),
+/// or `SNAP` for Snap. Returns no roots outside a detected bundle, so
+/// sanitization is a no-op for a normal system install.
+pub(crate) fn detect_bundle_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+
+    if env::var("APPIMAGE").is_ok() {
+        if let Ok(appdir) = env::var("APPDIR") {
+            let trimmed = appdir.trim();
+            if !trimmed.is_empty() {
+                roots.push(PathBuf::from(trimmed));
+            }
+        }
+    }
+
+    if Path::new("/.flatpak-info").exists() {
+        roots.push(PathBuf::from("/app"));
+    }
+
+    if let Ok(snap) = env::var("SNAP") {
+        let trimmed = snap.trim();
+        if !trimmed.is_empty() {
+            roots.push(PathBuf::from(trimmed));
+        }
+    }
+
+    roots
+}
+
+/// Drops every colon-separated entry of `value` that lives inside one of
+/// `roots`, deduping the rest while preserving first-seen order. Returns
+/// `None` if nothing survives, so the caller can unset the variable instead
+/// of leaving it set to `""`.
+fn strip_bundled_path_entries(value: &str, roots: &[PathBuf]) -> Option<String> {
+    let mut seen = HashSet::new();
+    let cleaned: Vec<&str> = value
+        .split(':')
+        .filter(|entry| !entry.is_empty())
+        .filter(|entry| !roots.iter().any(|root| Path::new(entry).starts_with(root)))
+        .filter(|entry| seen.insert(*entry))
+        .collect();
+
+    if cleaned.is_empty() {
+        None
+    } else {
+        Some(cleaned.join(":"))
+    }
+}
+
+/// Rebuilds `command`'s environment from scratch (`env_clear()`), carrying
+/// over every variable except the `PATH`-like ones, which get bundled
+/// entries stripped out. A no-op outside a detected AppImage/Flatpak/Snap
+/// bundle, since `detect_bundle_roots` returns nothing to strip.
+///
+/// Without this, an external opener like `xdg-open` inherits our bundled
+/// `LD_LIBRARY_PATH`/`GST_PLUGIN_PATH`/etc. and tries to load our bundled
+/// GTK/GStreamer libraries, which often crashes the host browser. Also used
+/// by `reveal.rs` when spawning the system file manager, for the same
+/// reason.
+pub(crate) fn sanitize_bundle_environment(command: &mut Command) {
+    let roots = detect_bundle_roots();
+    if roots.is_empty() {
+        return;
+    }
+
+    command.env_clear();
+    for (key, value) in env::vars() {
+        if BUNDLE_SENSITIVE_ENV_VARS.contains(&key.as_str()) {
+            if let Some(cleaned) = strip_bundled_path_entries(&value, &roots) {
+                command.env(key, cleaned);
+            }
+            continue;
+        }
+        command.env(key, value);
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn open_url_with_system_browser(url: &str) -> Result<(), String> {
-    Command::new("open")
+    let mut command = Command::new("open");
+    sanitize_bundle_environment(&mut command);
+    command
         .arg(url)
         .stdin(Stdio::null())
         .stdout(Stdio::null())
@@ -38,7 +141,9 @@ fn open_url_with_system_browser(url: &str) -> Result<(), String> {
 
 #[cfg(target_os = "windows")]
 fn open_url_with_system_browser(url: &str) -> Result<(), String> {
-    Command::new("rundll32")
+    let mut command = Command::new("rundll32");
+    sanitize_bundle_environment(&mut command);
+    command
         .args(["url.dll,FileProtocolHandler", url])
         .stdin(Stdio::null())
         .stdout(Stdio::null())
@@ -50,7 +155,9 @@ fn open_url_with_system_browser(url: &str) -> Result<(), String> {
 
 #[cfg(all(unix, not(target_os = "macos")))]
 fn open_url_with_system_browser(url: &str) -> Result<(), String> {
-    Command::new("xdg-open")
+    let mut command = Command::new("xdg-open");
+    sanitize_bundle_environment(&mut command);
+    command
         .arg(url)
         .stdin(Stdio::null())
         .stdout(Stdio::null())
@@ -65,6 +172,130 @@ fn open_url_with_system_browser(_url: &str) -> Result<(), String> {
     Err("Opening external URLs is not supported on this platform.".to_string())
 }
 
+#[cfg(target_os = "macos")]
+fn open_path_with_system_opener(path: &Path) -> Result<(), String> {
+    let mut command = Command::new("open");
+    sanitize_bundle_environment(&mut command);
+    command
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| format!("Failed to run 'open': {error}"))
+}
+
+#[cfg(target_os = "macos")]
+fn reveal_path_in_system_file_manager(path: &Path) -> Result<(), String> {
+    let mut command = Command::new("open");
+    sanitize_bundle_environment(&mut command);
+    command
+        .args([std::ffi::OsStr::new("-R"), path.as_os_str()])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| format!("Failed to run 'open -R': {error}"))
+}
+
+#[cfg(target_os = "windows")]
+fn open_path_with_system_opener(path: &Path) -> Result<(), String> {
+    let mut command = Command::new("explorer");
+    sanitize_bundle_environment(&mut command);
+    command
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| format!("Failed to run 'explorer': {error}"))
+}
+
+#[cfg(target_os = "windows")]
+fn reveal_path_in_system_file_manager(path: &Path) -> Result<(), String> {
+    let mut select_arg = std::ffi::OsString::from("/select,");
+    select_arg.push(path.as_os_str());
+
+    let mut command = Command::new("explorer");
+    sanitize_bundle_environment(&mut command);
+    command
+        .arg(select_arg)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| format!("Failed to run 'explorer /select,': {error}"))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn open_path_with_system_opener(path: &Path) -> Result<(), String> {
+    let mut command = Command::new("xdg-open");
+    sanitize_bundle_environment(&mut command);
+    command
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| format!("Failed to run 'xdg-open': {error}"))
+}
+
+/// Best-effort `org.freedesktop.FileManager1.ShowItems` call, which is the
+/// D-Bus-level equivalent of "reveal and select this file" most Linux file
+/// managers implement. `dbus-send` is assumed to be on `PATH`; a missing
+/// binary or an uncooperative file manager both just make this return
+/// `false` so the caller can fall back to opening the parent directory.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn reveal_via_dbus_show_items(path: &Path) -> bool {
+    let Some(path_str) = path.to_str() else {
+        return false;
+    };
+
+    let mut command = Command::new("dbus-send");
+    sanitize_bundle_environment(&mut command);
+    command
+        .args([
+            "--session",
+            "--dest=org.freedesktop.FileManager1",
+            "--type=method_call",
+            "/org/freedesktop/FileManager1",
+            "org.freedesktop.FileManager1.ShowItems",
+            &format!("array:string:file://{path_str}"),
+            "string:",
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn reveal_path_in_system_file_manager(path: &Path) -> Result<(), String> {
+    if reveal_via_dbus_show_items(path) {
+        return Ok(());
+    }
+
+    let parent_dir = path.parent().unwrap_or(path);
+    open_path_with_system_opener(parent_dir)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", unix)))]
+fn open_path_with_system_opener(_path: &Path) -> Result<(), String> {
+    Err("Opening local paths is not supported on this platform.".to_string())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", unix)))]
+fn reveal_path_in_system_file_manager(_path: &Path) -> Result<(), String> {
+    Err("Revealing local paths is not supported on this platform.".to_string())
+}
+
 #[tauri::command]
 pub(crate) fn desktop_bridge_is_desktop_runtime() -> bool {
     true
@@ -73,7 +304,22 @@ pub(crate) fn desktop_bridge_is_desktop_runtime() -> bool {
 #[tauri::command]
 pub(crate) fn desktop_bridge_get_backend_state(app_handle: AppHandle) -> BackendBridgeState {
     let state = app_handle.state::<BackendState>();
-    state.bridge_state(&app_handle)
+    state.bridge_state()
+}
+
+/// Emits the current `BackendBridgeState` once on
+/// [`backend_bridge_state::BACKEND_STATE_EVENT`], so a frontend that just
+/// subscribed (e.g. after a page reload) syncs up immediately instead of
+/// waiting for the next lifecycle transition. Subsequent changes arrive via
+/// that same event, so callers shouldn't need to poll
+/// `desktop_bridge_get_backend_state` again.
+#[tauri::command]
+pub(crate) fn desktop_bridge_subscribe_backend_state(app_handle: AppHandle) -> BackendBridgeResult {
+    backend_bridge_state::emit_backend_state(&app_handle);
+    BackendBridgeResult {
+        ok: true,
+        reason: None,
+    }
 }
 
 #[tauri::command]
@@ -102,7 +348,10 @@ pub(crate) async fn desktop_bridge_restart_backend(
         };
     }
 
-    restart_backend_flow::run_restart_backend_task(app_handle, auth_token).await
+    let result =
+        restart_backend_flow::run_restart_backend_task(app_handle.clone(), auth_token).await;
+    backend_bridge_state::emit_backend_state(&app_handle);
+    result
 }
 
 #[tauri::command]
@@ -115,7 +364,7 @@ pub(crate) fn desktop_bridge_stop_backend(app_handle: AppHandle) -> BackendBridg
         };
     }
 
-    match state.stop_backend_for_bridge() {
+    let result = match state.stop_backend_for_bridge() {
         Ok(()) => BackendBridgeResult {
             ok: true,
             reason: None,
@@ -124,7 +373,9 @@ pub(crate) fn desktop_bridge_stop_backend(app_handle: AppHandle) -> BackendBridg
             ok: false,
             reason: Some(error),
         },
-    }
+    };
+    backend_bridge_state::emit_backend_state(&app_handle);
+    result
 }
 
 #[tauri::command]
@@ -151,6 +402,62 @@ pub(crate) fn desktop_bridge_open_external_url(url: String) -> BackendBridgeResu
     }
 }
 
+#[tauri::command]
+pub(crate) fn desktop_bridge_open_path(path: String) -> BackendBridgeResult {
+    let resolved_path =
+        match runtime_paths::canonicalize_within_allowed_roots(Path::new(path.trim())) {
+            Ok(resolved) => resolved,
+            Err(error) => {
+                return BackendBridgeResult {
+                    ok: false,
+                    reason: Some(error),
+                };
+            }
+        };
+
+    match open_path_with_system_opener(&resolved_path) {
+        Ok(()) => BackendBridgeResult {
+            ok: true,
+            reason: None,
+        },
+        Err(error) => {
+            append_desktop_log(&error);
+            BackendBridgeResult {
+                ok: false,
+                reason: Some(error),
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub(crate) fn desktop_bridge_reveal_in_file_manager(path: String) -> BackendBridgeResult {
+    let resolved_path =
+        match runtime_paths::canonicalize_within_allowed_roots(Path::new(path.trim())) {
+            Ok(resolved) => resolved,
+            Err(error) => {
+                return BackendBridgeResult {
+                    ok: false,
+                    reason: Some(error),
+                };
+            }
+        };
+
+    match reveal_path_in_system_file_manager(&resolved_path) {
+        Ok(()) => BackendBridgeResult {
+            ok: true,
+            reason: None,
+        },
+        Err(error) => {
+            append_desktop_log(&error);
+            BackendBridgeResult {
+                ok: false,
+                reason: Some(error),
+            }
+        }
+    }
+}
+
 #[tauri::command]
 pub(crate) fn desktop_bridge_set_shell_locale(
     app_handle: AppHandle,
@@ -179,13 +486,121 @@ pub(crate) fn desktop_bridge_set_shell_locale(
     }
 }
 
+#[tauri::command]
+pub(crate) fn desktop_bridge_list_shell_locales() -> Vec<String> {
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    shell_locale::available_locale_catalogs(packaged_root_dir.as_deref())
+}
+
+#[tauri::command]
+pub(crate) fn desktop_bridge_cancel_update(app_handle: AppHandle) -> BackendBridgeResult {
+    let update_state = app_handle.state::<UpdateState>();
+    if update_state.request_cancel() {
+        append_desktop_log(
+            "desktop bridge requested cancellation of the in-progress update download",
+        );
+        BackendBridgeResult {
+            ok: true,
+            reason: None,
+        }
+    } else {
+        BackendBridgeResult {
+            ok: false,
+            reason: Some("No update download is currently in progress.".to_string()),
+        }
+    }
+}
+
+#[tauri::command]
+pub(crate) fn desktop_bridge_apply_external_update(app_handle: AppHandle) -> BackendBridgeResult {
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    let current_version = app_handle.package_info().version.to_string();
+
+    let Some(plan) = external_update::find_pending_external_update(
+        packaged_root_dir.as_deref(),
+        &current_version,
+    ) else {
+        return BackendBridgeResult {
+            ok: false,
+            reason: Some("No valid local update manifest found.".to_string()),
+        };
+    };
+
+    match external_update::install_external_update(&app_handle, &plan) {
+        Ok(()) => BackendBridgeResult {
+            ok: true,
+            reason: None,
+        },
+        Err(error) => {
+            append_desktop_log(&error);
+            BackendBridgeResult {
+                ok: false,
+                reason: Some(error),
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub(crate) fn desktop_bridge_set_update_channel(
+    app_handle: AppHandle,
+    channel: String,
+) -> BackendBridgeResult {
+    let Some(channel) = UpdateChannel::from_str(&channel) else {
+        return BackendBridgeResult {
+            ok: false,
+            reason: Some(format!("Unknown update channel: {channel}")),
+        };
+    };
+
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    if let Err(error) =
+        shell_locale::write_cached_update_channel(channel, packaged_root_dir.as_deref())
+    {
+        append_desktop_log(&format!(
+            "failed to persist update channel setting: {error}"
+        ));
+        return BackendBridgeResult {
+            ok: false,
+            reason: Some(error),
+        };
+    }
+
+    if let Some(state) = app_handle.try_state::<UpdateChannelState>() {
+        state.set(channel);
+    }
+    tray_labels::set_update_channel_label(
+        &app_handle,
+        DEFAULT_SHELL_LOCALE,
+        channel,
+        append_desktop_log,
+    );
+    append_desktop_log(&format!(
+        "desktop bridge switched update channel to {}",
+        channel.as_str()
+    ));
+
+    BackendBridgeResult {
+        ok: true,
+        reason: None,
+    }
+}
+
+fn current_update_channel(app_handle: &AppHandle) -> UpdateChannel {
+    app_handle
+        .try_state::<UpdateChannelState>()
+        .map(|state| state.current())
+        .unwrap_or_default()
+}
+
 #[tauri::command]
 pub(crate) async fn desktop_bridge_check_desktop_app_update(
     app_handle: AppHandle,
 ) -> DesktopAppUpdateCheckResult {
     let current_version = app_handle.package_info().version.to_string();
+    let channel = current_update_channel(&app_handle);
 
-    let updater = match app_handle.updater() {
+    let updater = match update_channel::build_updater_for_channel(&app_handle, channel) {
         Ok(updater) => updater,
         Err(error) => {
             let reason = format!("Failed to initialize updater: {error}");
@@ -196,6 +611,7 @@ pub(crate) async fn desktop_bridge_check_desktop_app_update(
                 current_version,
                 latest_version: None,
                 has_update: false,
+                channel,
             };
         }
     };
@@ -207,6 +623,7 @@ pub(crate) async fn desktop_bridge_check_desktop_app_update(
             current_version,
             latest_version: Some(update.version.to_string()),
             has_update: true,
+            channel,
         },
         Ok(None) => DesktopAppUpdateCheckResult {
             ok: true,
@@ -214,6 +631,7 @@ pub(crate) async fn desktop_bridge_check_desktop_app_update(
             current_version: current_version.clone(),
             latest_version: Some(current_version),
             has_update: false,
+            channel,
         },
         Err(error) => {
             // 静默处理网络错误（如 latest.json 不存在），只记录日志
@@ -226,6 +644,7 @@ pub(crate) async fn desktop_bridge_check_desktop_app_update(
                 current_version,
                 latest_version: None,
                 has_update: false,
+                channel,
             }
         }
     }
@@ -237,7 +656,8 @@ pub(crate) async fn desktop_bridge_install_desktop_app_update(
 ) -> BackendBridgeResult {
     use tauri_plugin_dialog::DialogExt;
 
-    let updater = match app_handle.updater() {
+    let channel = current_update_channel(&app_handle);
+    let updater = match update_channel::build_updater_for_channel(&app_handle, channel) {
         Ok(updater) => updater,
         Err(error) => {
             let reason = format!("Failed to initialize updater: {error}");
@@ -269,26 +689,39 @@ pub(crate) async fn desktop_bridge_install_desktop_app_update(
 
     let target_version = update.version.to_string();
 
-    // 下载更新（带进度回调 + 下载完成回调）
-    let downloaded_bytes = match update.download(|_, _| {}, || {}).await {
-        Ok(bytes) => bytes,
-        Err(error) => {
-            let reason = format!("Failed to download desktop app update: {error}");
-            append_desktop_log(&reason);
-            return BackendBridgeResult {
-                ok: false,
-                reason: Some(reason),
-            };
-        }
-    };
+    // 下载更新，进度通过 desktop-update://download-started / -progress / -finished 事件推送给前端
+    let downloaded_bytes =
+        match update_download::download_update_with_progress(&app_handle, &update).await {
+            UpdateDownloadOutcome::Downloaded(bytes) => bytes,
+            UpdateDownloadOutcome::AlreadyInProgress => {
+                return BackendBridgeResult {
+                    ok: false,
+                    reason: Some("An update download is already in progress.".to_string()),
+                };
+            }
+            UpdateDownloadOutcome::Cancelled => {
+                append_desktop_log("update download cancelled before installation");
+                return BackendBridgeResult {
+                    ok: true,
+                    reason: Some("cancelled".to_string()),
+                };
+            }
+            UpdateDownloadOutcome::Failed(reason) => {
+                append_desktop_log(&reason);
+                return BackendBridgeResult {
+                    ok: false,
+                    reason: Some(reason),
+                };
+            }
+        };
 
     append_desktop_log(&format!(
         "desktop app update {target_version} downloaded, prompting user for installation"
     ));
 
-    // 下载完成后，弹出对话框询问用户是否安装
-    let dialog = app_handle.dialog();
-    let should_install = dialog
+    // 下载完成后，弹出对话框询问用户是否立即安装
+    let should_install_now = app_handle
+        .dialog()
         .message(format!(
             "新版本 {} 已下载完成，是否立即安装并重启应用？",
             target_version
@@ -298,11 +731,48 @@ pub(crate) async fn desktop_bridge_install_desktop_app_update(
         .buttons(tauri_plugin_dialog::MessageDialogButtons::YesNo)
         .blocking_show();
 
-    if !should_install {
-        append_desktop_log("user declined to install update");
-        return BackendBridgeResult {
-            ok: true,
-            reason: Some("user declined".to_string()),
+    if !should_install_now {
+        // 用户选择不立即安装，再询问是否改为下次退出应用时自动安装
+        let should_install_on_quit = app_handle
+            .dialog()
+            .message("是否改为在下次退出应用时自动安装该更新？")
+            .title("稍后安装")
+            .kind(tauri_plugin_dialog::MessageDialogKind::Info)
+            .buttons(tauri_plugin_dialog::MessageDialogButtons::YesNo)
+            .blocking_show();
+
+        if !should_install_on_quit {
+            append_desktop_log("user declined to install update");
+            return BackendBridgeResult {
+                ok: true,
+                reason: Some("user declined".to_string()),
+            };
+        }
+
+        let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+        return match staged_update::write_staged_update(
+            packaged_root_dir.as_deref(),
+            &target_version,
+            &downloaded_bytes,
+        ) {
+            Ok(()) => {
+                append_desktop_log(&format!(
+                    "desktop app update {target_version} staged for install on next quit"
+                ));
+                BackendBridgeResult {
+                    ok: true,
+                    reason: Some("staged for install on quit".to_string()),
+                }
+            }
+            Err(error) => {
+                append_desktop_log(&format!(
+                    "failed to stage update for install on quit: {error}"
+                ));
+                BackendBridgeResult {
+                    ok: false,
+                    reason: Some(error),
+                }
+            }
         };
     }
 
@@ -326,3 +796,183 @@ pub(crate) async fn desktop_bridge_install_desktop_app_update(
         reason: None,
     }
 }
+
+/// Like [`desktop_bridge_install_desktop_app_update`], but stops once the
+/// update archive is downloaded instead of also installing and restarting.
+/// Lets the UI drive its own "install now" confirmation off the
+/// `desktop-update://download-finished` event rather than the blocking
+/// native dialog the auto-update flows use.
+#[tauri::command]
+pub(crate) async fn desktop_bridge_download_desktop_app_update(
+    app_handle: AppHandle,
+) -> BackendBridgeResult {
+    let channel = current_update_channel(&app_handle);
+    let updater = match update_channel::build_updater_for_channel(&app_handle, channel) {
+        Ok(updater) => updater,
+        Err(error) => {
+            let reason = format!("Failed to initialize updater: {error}");
+            append_desktop_log(&reason);
+            return BackendBridgeResult {
+                ok: false,
+                reason: Some(reason),
+            };
+        }
+    };
+
+    let update = match updater.check().await {
+        Ok(Some(update)) => update,
+        Ok(None) => {
+            return BackendBridgeResult {
+                ok: false,
+                reason: Some("Already on latest desktop version.".to_string()),
+            };
+        }
+        Err(error) => {
+            let reason = format!("Failed to check desktop app update: {error}");
+            append_desktop_log(&reason);
+            return BackendBridgeResult {
+                ok: false,
+                reason: Some(reason),
+            };
+        }
+    };
+
+    let target_version = update.version.to_string();
+
+    match update_download::download_update_with_progress(&app_handle, &update).await {
+        UpdateDownloadOutcome::Downloaded(bytes) => {
+            app_handle
+                .state::<PendingUpdateState>()
+                .store(target_version.clone(), bytes);
+            append_desktop_log(&format!(
+                "desktop app update {target_version} downloaded and ready to install"
+            ));
+            BackendBridgeResult {
+                ok: true,
+                reason: None,
+            }
+        }
+        UpdateDownloadOutcome::AlreadyInProgress => BackendBridgeResult {
+            ok: false,
+            reason: Some("An update download is already in progress.".to_string()),
+        },
+        UpdateDownloadOutcome::Cancelled => {
+            append_desktop_log("update download cancelled");
+            BackendBridgeResult {
+                ok: true,
+                reason: Some("cancelled".to_string()),
+            }
+        }
+        UpdateDownloadOutcome::Failed(reason) => {
+            append_desktop_log(&reason);
+            BackendBridgeResult {
+                ok: false,
+                reason: Some(reason),
+            }
+        }
+    }
+}
+
+/// Installs a desktop-app update archive previously cached by
+/// [`desktop_bridge_download_desktop_app_update`]. Re-checks for the update
+/// first so installation still fails closed if a newer version has since
+/// appeared and the cached bytes no longer match.
+#[tauri::command]
+pub(crate) async fn desktop_bridge_install_pending_update(
+    app_handle: AppHandle,
+) -> BackendBridgeResult {
+    let channel = current_update_channel(&app_handle);
+    let updater = match update_channel::build_updater_for_channel(&app_handle, channel) {
+        Ok(updater) => updater,
+        Err(error) => {
+            let reason = format!("Failed to initialize updater: {error}");
+            append_desktop_log(&reason);
+            return BackendBridgeResult {
+                ok: false,
+                reason: Some(reason),
+            };
+        }
+    };
+
+    let update = match updater.check().await {
+        Ok(Some(update)) => update,
+        Ok(None) => {
+            return BackendBridgeResult {
+                ok: false,
+                reason: Some("Already on latest desktop version.".to_string()),
+            };
+        }
+        Err(error) => {
+            let reason = format!("Failed to check desktop app update: {error}");
+            append_desktop_log(&reason);
+            return BackendBridgeResult {
+                ok: false,
+                reason: Some(reason),
+            };
+        }
+    };
+
+    let target_version = update.version.to_string();
+    let Some(downloaded_bytes) = app_handle
+        .state::<PendingUpdateState>()
+        .take_for_version(&target_version)
+    else {
+        return BackendBridgeResult {
+            ok: false,
+            reason: Some(
+                "No downloaded update pending installation; download it again first.".to_string(),
+            ),
+        };
+    };
+
+    if let Err(error) = update.install(&downloaded_bytes) {
+        let reason = format!("Failed to install desktop app update: {error}");
+        append_desktop_log(&reason);
+        return BackendBridgeResult {
+            ok: false,
+            reason: Some(reason),
+        };
+    }
+
+    append_desktop_log(&format!(
+        "desktop app update installed to version {target_version}; restarting app"
+    ));
+    app_handle.request_restart();
+
+    BackendBridgeResult {
+        ok: true,
+        reason: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_bundled_path_entries;
+    use std::path::PathBuf;
+
+    #[test]
+    fn drops_entries_inside_a_bundle_root() {
+        let roots = vec![PathBuf::from("/tmp/.mount_AstrBotAbc123")];
+        let cleaned = strip_bundled_path_entries(
+            "/tmp/.mount_AstrBotAbc123/usr/bin:/usr/local/bin:/usr/bin",
+            &roots,
+        );
+        assert_eq!(cleaned, Some("/usr/local/bin:/usr/bin".to_string()));
+    }
+
+    #[test]
+    fn dedupes_while_preserving_first_seen_order() {
+        let cleaned = strip_bundled_path_entries("/usr/bin:/usr/local/bin:/usr/bin", &[]);
+        assert_eq!(cleaned, Some("/usr/bin:/usr/local/bin".to_string()));
+    }
+
+    #[test]
+    fn returns_none_when_every_entry_is_stripped() {
+        let roots = vec![PathBuf::from("/tmp/.mount_AstrBotAbc123")];
+        let cleaned = strip_bundled_path_entries(
+            "/tmp/.mount_AstrBotAbc123/usr/bin:/tmp/.mount_AstrBotAbc123/usr/lib",
+            &roots,
+        );
+        assert_eq!(cleaned, None);
+    }
+}
@@ -0,0 +1,47 @@
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    append_shutdown_log, backend_bridge_state, process_control, runtime_paths, staged_update,
+    BackendState,
+};
+
+/// Called when the app is about to exit (tray quit, OS shutdown, etc). Delays
+/// the actual exit, on a background thread, for two reasons: to give
+/// [`process_control::stop_child_process`] a chance to shut the backend child
+/// down gracefully instead of letting the process tree get torn down mid
+/// write, and to apply any update staged via "install on quit" so it takes
+/// effect before the next launch.
+pub(crate) fn handle_exit_requested(app_handle: &AppHandle, api: &tauri::ExitRequestApi) {
+    let state = app_handle.state::<BackendState>();
+    let child = match state.child.lock() {
+        Ok(mut guard) => guard.take(),
+        Err(_) => None,
+    };
+
+    let has_staged_update =
+        staged_update::staged_update_version(runtime_paths::default_packaged_root_dir().as_deref())
+            .is_some();
+
+    if child.is_none() && !has_staged_update {
+        return;
+    }
+
+    api.prevent_exit();
+    backend_bridge_state::emit_backend_state(app_handle);
+
+    let app_handle = app_handle.clone();
+    std::thread::spawn(move || {
+        if let Some(mut child) = child {
+            append_shutdown_log("exit requested, stopping backend process gracefully");
+            process_control::stop_child_process(&mut child);
+            append_shutdown_log("backend process stopped");
+        }
+
+        staged_update::install_staged_update_if_present(&app_handle);
+
+        append_shutdown_log("exiting desktop process");
+        app_handle.exit(0);
+    });
+}
+
+pub(crate) fn handle_exit_event(_app_handle: &AppHandle) {}
@@ -0,0 +1,271 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use serde::Deserialize;
+use tauri::AppHandle;
+
+use crate::append_desktop_log;
+
+const EXTERNAL_UPDATE_MANIFEST_FILE: &str = "external_update.json";
+
+#[derive(Debug, Deserialize)]
+struct ExternalUpdateManifest {
+    version: String,
+    artifact: String,
+    /// An operator-supplied marker confirming the manifest wasn't left as an
+    /// unedited template. This is NOT a cryptographic signature and is not
+    /// verified against any key — it's checked only for non-emptiness. The
+    /// air-gapped artifact's actual integrity still rests entirely on the
+    /// operator's own deployment process (e.g. copying it over a trusted
+    /// channel); there is no on-disk verification of artifact authenticity.
+    unverified_marker: String,
+}
+
+/// A local update that is newer than the running version, whose artifact
+/// file exists and whose manifest's `unverified_marker` is non-empty.
+#[derive(Debug, Clone)]
+pub(crate) struct ExternalUpdatePlan {
+    pub(crate) version: String,
+    pub(crate) artifact_path: PathBuf,
+}
+
+fn manifest_path(packaged_root_dir: Option<&Path>) -> Option<PathBuf> {
+    packaged_root_dir.map(|root| root.join(EXTERNAL_UPDATE_MANIFEST_FILE))
+}
+
+fn parse_version(raw: &str) -> Option<semver::Version> {
+    semver::Version::parse(raw.trim()).ok()
+}
+
+/// Looks for `external_update.json` beside the packaged install for
+/// air-gapped deployments. Returns a plan only when the manifest's
+/// `version` is strictly newer than `current_version` (compared with
+/// semver), its `artifact` file exists next to the manifest, and its
+/// `unverified_marker` is non-empty (a basic sanity check that the
+/// manifest wasn't left as an unedited template — it is not a
+/// cryptographic signature and provides no integrity guarantee); any
+/// other condition is logged and treated as "no local update available"
+/// so the caller falls back to the network updater.
+pub(crate) fn find_pending_external_update(
+    packaged_root_dir: Option<&Path>,
+    current_version: &str,
+) -> Option<ExternalUpdatePlan> {
+    let manifest_path = manifest_path(packaged_root_dir)?;
+    let raw = fs::read_to_string(&manifest_path).ok()?;
+    let manifest: ExternalUpdateManifest = match serde_json::from_str(&raw) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            append_desktop_log(&format!(
+                "failed to parse external update manifest {}: {error}",
+                manifest_path.display()
+            ));
+            return None;
+        }
+    };
+
+    let current = parse_version(current_version)?;
+    let candidate = match parse_version(&manifest.version) {
+        Some(version) => version,
+        None => {
+            append_desktop_log(&format!(
+                "external update manifest declares an invalid version '{}'",
+                manifest.version
+            ));
+            return None;
+        }
+    };
+
+    if candidate <= current {
+        return None;
+    }
+
+    if manifest.unverified_marker.trim().is_empty() {
+        append_desktop_log(&format!(
+            "external update manifest declares version {} but its unverified_marker is empty; skipping",
+            manifest.version
+        ));
+        return None;
+    }
+
+    let artifact_path = manifest_path.parent()?.join(&manifest.artifact);
+    if !artifact_path.is_file() {
+        append_desktop_log(&format!(
+            "external update manifest declares artifact {} but it is missing; skipping",
+            artifact_path.display()
+        ));
+        return None;
+    }
+
+    Some(ExternalUpdatePlan {
+        version: manifest.version,
+        artifact_path,
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn spawn_artifact_installer(artifact_path: &Path) -> Result<(), String> {
+    Command::new(artifact_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| {
+            format!(
+                "Failed to launch installer {}: {error}",
+                artifact_path.display()
+            )
+        })
+}
+
+#[cfg(target_os = "macos")]
+fn spawn_artifact_installer(artifact_path: &Path) -> Result<(), String> {
+    Command::new("open")
+        .arg(artifact_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| {
+            format!(
+                "Failed to launch installer {}: {error}",
+                artifact_path.display()
+            )
+        })
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn spawn_artifact_installer(artifact_path: &Path) -> Result<(), String> {
+    Command::new("xdg-open")
+        .arg(artifact_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| {
+            format!(
+                "Failed to launch installer {}: {error}",
+                artifact_path.display()
+            )
+        })
+}
+
+/// Mirrors the `update.install()` / `request_restart()` tail in
+/// `app_runtime::run()`, but for an artifact staged on disk instead of
+/// one downloaded from the network: confirms the artifact bytes are
+/// readable, hands the file to the platform installer, and restarts the
+/// app so the new version takes over.
+pub(crate) fn install_external_update(
+    app_handle: &AppHandle,
+    plan: &ExternalUpdatePlan,
+) -> Result<(), String> {
+    fs::read(&plan.artifact_path).map_err(|error| {
+        format!(
+            "Failed to read external update artifact {}: {error}",
+            plan.artifact_path.display()
+        )
+    })?;
+
+    spawn_artifact_installer(&plan.artifact_path)?;
+
+    append_desktop_log(&format!(
+        "external update {} launched from {}; restarting app",
+        plan.version,
+        plan.artifact_path.display()
+    ));
+    app_handle.request_restart();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_root(label: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "astrbot-desktop-test-{}-external_update_{label}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("create temp root dir");
+        root
+    }
+
+    fn write_manifest(root: &Path, manifest: &str, artifact_contents: Option<&str>) {
+        fs::write(root.join(EXTERNAL_UPDATE_MANIFEST_FILE), manifest).expect("write manifest");
+        if let Some(contents) = artifact_contents {
+            fs::write(root.join("artifact.bin"), contents).expect("write artifact");
+        }
+    }
+
+    #[test]
+    fn parse_version_rejects_invalid_semver() {
+        assert!(parse_version("not-a-version").is_none());
+        assert!(parse_version("1.2").is_none());
+        assert!(parse_version("1.2.3").is_some());
+    }
+
+    #[test]
+    fn find_pending_external_update_rejects_version_not_newer_than_current() {
+        let root = temp_root("not_newer");
+        write_manifest(
+            &root,
+            r#"{"version": "1.0.0", "artifact": "artifact.bin", "unverified_marker": "ok"}"#,
+            Some("bytes"),
+        );
+
+        assert!(find_pending_external_update(Some(&root), "1.0.0").is_none());
+        assert!(find_pending_external_update(Some(&root), "2.0.0").is_none());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn find_pending_external_update_rejects_empty_unverified_marker() {
+        let root = temp_root("empty_marker");
+        write_manifest(
+            &root,
+            r#"{"version": "2.0.0", "artifact": "artifact.bin", "unverified_marker": "  "}"#,
+            Some("bytes"),
+        );
+
+        assert!(find_pending_external_update(Some(&root), "1.0.0").is_none());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn find_pending_external_update_rejects_missing_artifact() {
+        let root = temp_root("missing_artifact");
+        write_manifest(
+            &root,
+            r#"{"version": "2.0.0", "artifact": "artifact.bin", "unverified_marker": "ok"}"#,
+            None,
+        );
+
+        assert!(find_pending_external_update(Some(&root), "1.0.0").is_none());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn find_pending_external_update_accepts_a_valid_newer_manifest() {
+        let root = temp_root("valid");
+        write_manifest(
+            &root,
+            r#"{"version": "2.0.0", "artifact": "artifact.bin", "unverified_marker": "ok"}"#,
+            Some("bytes"),
+        );
+
+        let plan = find_pending_external_update(Some(&root), "1.0.0").expect("plan present");
+        assert_eq!(plan.version, "2.0.0");
+        assert_eq!(plan.artifact_path, root.join("artifact.bin"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,60 @@
+/// A minimally-parsed HTTP/1.x response: just enough for a readiness probe
+/// to read the status line and buffer the body.
+#[derive(Debug)]
+pub(crate) struct HttpResponse {
+    pub(crate) status: u16,
+    pub(crate) body: String,
+}
+
+impl HttpResponse {
+    pub(crate) fn is_server_error(&self) -> bool {
+        self.status >= 500
+    }
+}
+
+/// Parses a raw HTTP/1.x response read off the wire. Returns `None` if the
+/// status line is missing or malformed; tolerates a body that isn't valid
+/// UTF-8 by lossily converting it, since probes only need to substring- or
+/// JSON-match it, not round-trip it.
+pub(crate) fn parse_http_response(raw: &[u8]) -> Option<HttpResponse> {
+    let header_end = find_header_terminator(raw)?;
+    let head = std::str::from_utf8(&raw[..header_end]).ok()?;
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next()?;
+    let status = status_line.split_whitespace().nth(1)?.parse::<u16>().ok()?;
+
+    let body_start = header_end + 4;
+    let body = String::from_utf8_lossy(&raw[body_start..]).into_owned();
+
+    Some(HttpResponse { status, body })
+}
+
+fn find_header_terminator(raw: &[u8]) -> Option<usize> {
+    raw.windows(4).position(|window| window == b"\r\n\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_http_response;
+
+    #[test]
+    fn parses_status_and_body_from_a_well_formed_response() {
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"version\":\"1.0\"}";
+        let response = parse_http_response(raw).expect("should parse");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "{\"version\":\"1.0\"}");
+        assert!(!response.is_server_error());
+    }
+
+    #[test]
+    fn flags_5xx_statuses_as_server_errors() {
+        let raw = b"HTTP/1.1 502 Bad Gateway\r\n\r\n";
+        let response = parse_http_response(raw).expect("should parse");
+        assert!(response.is_server_error());
+    }
+
+    #[test]
+    fn returns_none_for_a_response_missing_the_header_terminator() {
+        assert!(parse_http_response(b"not an http response").is_none());
+    }
+}
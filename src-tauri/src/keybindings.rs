@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use crate::{shell_locale, tray_actions};
+
+/// Built-in accelerators for the tray/menu actions that warrant one. Actions
+/// without an entry here (and without a user override) get no accelerator.
+fn default_accelerator(action_id: &str) -> Option<&'static str> {
+    match action_id {
+        tray_actions::TRAY_MENU_TOGGLE_WINDOW => Some("CmdOrCtrl+Shift+A"),
+        tray_actions::TRAY_MENU_RELOAD_WINDOW => Some("CmdOrCtrl+R"),
+        tray_actions::TRAY_MENU_RESTART_BACKEND => Some("CmdOrCtrl+Shift+R"),
+        tray_actions::TRAY_MENU_CHECK_UPDATE => Some("CmdOrCtrl+U"),
+        tray_actions::TRAY_MENU_OPEN_LOGS => Some("CmdOrCtrl+L"),
+        tray_actions::TRAY_MENU_QUIT => Some("CmdOrCtrl+Q"),
+        _ => None,
+    }
+}
+
+const ACCELERATOR_MODIFIERS: &[&str] = &[
+    "cmdorctrl",
+    "commandorcontrol",
+    "cmd",
+    "command",
+    "ctrl",
+    "control",
+    "alt",
+    "option",
+    "altgr",
+    "shift",
+    "super",
+    "meta",
+];
+
+/// Minimal, sufficient validator for the `Cmd+Shift+A`-style accelerator
+/// strings `MenuItem::with_id` accepts: every segment but the last must be a
+/// known modifier, and the last segment (the actual key) must be non-empty
+/// and either a single character or purely alphanumeric (`R`, `F5`, `Space`).
+/// This doesn't attempt to reject every string `muda` itself would refuse —
+/// it only needs to catch obviously-malformed overrides (typos, stray
+/// punctuation, empty segments) before they reach menu construction, where a
+/// conversion failure there would `?`-propagate and take down the whole
+/// native menu bar instead of just the one binding.
+fn is_valid_accelerator(accelerator: &str) -> bool {
+    let segments: Vec<&str> = accelerator.split('+').collect();
+    let Some((key, modifiers)) = segments.split_last() else {
+        return false;
+    };
+
+    let key = key.trim();
+    if key.is_empty() {
+        return false;
+    }
+    if key.chars().count() > 1 && !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+
+    modifiers.iter().all(|modifier| {
+        !modifier.trim().is_empty()
+            && ACCELERATOR_MODIFIERS.contains(&modifier.to_lowercase().as_str())
+    })
+}
+
+/// Resolved menu accelerators: user overrides from `desktop_state.json`
+/// layered over [`default_accelerator`].
+pub(crate) struct Keymap {
+    overrides: std::collections::HashMap<String, String>,
+}
+
+impl Keymap {
+    pub(crate) fn accelerator_for(&self, action_id: &str) -> Option<String> {
+        if let Some(accelerator) = self.overrides.get(action_id) {
+            return Some(accelerator.clone());
+        }
+        default_accelerator(action_id).map(str::to_string)
+    }
+}
+
+/// Loads user keybinding overrides and drops any that don't parse as a
+/// syntactically valid accelerator, so a single malformed override (absent
+/// or malformed) falls back to [`default_accelerator`] for just that action
+/// instead of aborting the entire menu via a failed `MenuItem::with_id`.
+pub(crate) fn resolve_keymap(packaged_root_dir: Option<&Path>) -> Keymap {
+    let overrides = shell_locale::read_cached_keybindings(packaged_root_dir)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(_, accelerator)| is_valid_accelerator(accelerator))
+        .collect();
+
+    Keymap { overrides }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accelerator_for_falls_back_to_default_when_no_override() {
+        let keymap = Keymap {
+            overrides: std::collections::HashMap::new(),
+        };
+        assert_eq!(
+            keymap.accelerator_for(tray_actions::TRAY_MENU_RELOAD_WINDOW),
+            Some("CmdOrCtrl+R".to_string())
+        );
+        assert_eq!(
+            keymap.accelerator_for(tray_actions::TRAY_MENU_CANCEL_UPDATE),
+            None
+        );
+    }
+
+    #[test]
+    fn accelerator_for_prefers_user_override() {
+        let mut overrides = std::collections::HashMap::new();
+        overrides.insert(
+            tray_actions::TRAY_MENU_RELOAD_WINDOW.to_string(),
+            "CmdOrCtrl+Alt+R".to_string(),
+        );
+        let keymap = Keymap { overrides };
+        assert_eq!(
+            keymap.accelerator_for(tray_actions::TRAY_MENU_RELOAD_WINDOW),
+            Some("CmdOrCtrl+Alt+R".to_string())
+        );
+    }
+
+    #[test]
+    fn is_valid_accelerator_accepts_known_modifiers() {
+        assert!(is_valid_accelerator("CmdOrCtrl+Shift+A"));
+        assert!(is_valid_accelerator("CmdOrCtrl+R"));
+        assert!(is_valid_accelerator("F5"));
+    }
+
+    #[test]
+    fn is_valid_accelerator_rejects_malformed_strings() {
+        assert!(!is_valid_accelerator("not-a-key"));
+        assert!(!is_valid_accelerator("CmdOrCtrl+"));
+        assert!(!is_valid_accelerator("Bogus+R"));
+        assert!(!is_valid_accelerator(""));
+    }
+
+    #[test]
+    fn resolve_keymap_drops_malformed_override_and_falls_back_to_default() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "astrbot-desktop-test-{}-{}",
+            std::process::id(),
+            "keybindings_malformed_override"
+        ));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        let state_path = temp_dir.join("data").join("desktop_state.json");
+        std::fs::create_dir_all(state_path.parent().unwrap()).expect("create data dir");
+        std::fs::write(
+            &state_path,
+            r#"{"keybindings": {"tray_reload_window": "not-a-key"}}"#,
+        )
+        .expect("write desktop state");
+
+        let keymap = resolve_keymap(Some(&temp_dir));
+        assert_eq!(
+            keymap.accelerator_for(tray_actions::TRAY_MENU_RELOAD_WINDOW),
+            Some("CmdOrCtrl+R".to_string())
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
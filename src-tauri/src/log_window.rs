@@ -0,0 +1,89 @@
+use std::sync::OnceLock;
+
+use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+
+pub(crate) const LOG_WINDOW_LABEL: &str = "logs";
+pub(crate) const LOG_LINE_EVENT: &str = "desktop://log-line";
+
+static LOG_BROADCAST_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LogLinePayload {
+    pub(crate) line: String,
+}
+
+/// Records the app handle so log lines can be broadcast to the log
+/// window from anywhere in the process via [`broadcast_log_line`], which is
+/// called both by the backend stdout/stderr relay in `backend_launch.rs` and
+/// by the desktop app's own `append_desktop_log`/`append_startup_log`/
+/// `append_restart_log`/`append_shutdown_log` helpers in `app_helpers.rs`,
+/// so the log window streams both sides live.
+pub(crate) fn register_log_broadcast_handle(app_handle: &AppHandle) {
+    let _ = LOG_BROADCAST_HANDLE.set(app_handle.clone());
+}
+
+pub(crate) fn broadcast_log_line(line: &str) {
+    let Some(app_handle) = LOG_BROADCAST_HANDLE.get() else {
+        return;
+    };
+
+    let _ = app_handle.emit_to(
+        LOG_WINDOW_LABEL,
+        LOG_LINE_EVENT,
+        LogLinePayload {
+            line: line.to_string(),
+        },
+    );
+}
+
+fn ensure_log_window(app_handle: &AppHandle) -> Result<WebviewWindow, String> {
+    if let Some(window) = app_handle.get_webview_window(LOG_WINDOW_LABEL) {
+        return Ok(window);
+    }
+
+    WebviewWindowBuilder::new(
+        app_handle,
+        LOG_WINDOW_LABEL,
+        WebviewUrl::App("logs.html".into()),
+    )
+    .title("AstrBot Logs")
+    .inner_size(720.0, 480.0)
+    .visible(false)
+    .build()
+    .map_err(|error| format!("Failed to create log window: {error}"))
+}
+
+pub(crate) fn show_log_window<F>(app_handle: &AppHandle, log: F)
+where
+    F: Fn(&str),
+{
+    let window = match ensure_log_window(app_handle) {
+        Ok(window) => window,
+        Err(error) => {
+            log(&error);
+            return;
+        }
+    };
+
+    if let Err(error) = window.show() {
+        log(&format!("failed to show log window: {error}"));
+        return;
+    }
+    if let Err(error) = window.set_focus() {
+        log(&format!("failed to focus log window: {error}"));
+    }
+}
+
+pub(crate) fn hide_log_window<F>(app_handle: &AppHandle, log: F)
+where
+    F: Fn(&str),
+{
+    let Some(window) = app_handle.get_webview_window(LOG_WINDOW_LABEL) else {
+        return;
+    };
+
+    if let Err(error) = window.hide() {
+        log(&format!("failed to hide log window: {error}"));
+    }
+}
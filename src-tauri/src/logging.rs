@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+/// Resolves where a desktop-side log file lives: `<packaged root>/logs/<file_name>`,
+/// or a temp-dir fallback when no packaged root can be resolved (e.g. no home
+/// directory), so logging never silently no-ops.
+pub(crate) fn resolve_desktop_log_path(
+    packaged_root_dir: Option<PathBuf>,
+    file_name: &str,
+) -> PathBuf {
+    packaged_root_dir
+        .unwrap_or_else(std::env::temp_dir)
+        .join("logs")
+        .join(file_name)
+}
@@ -2,10 +2,13 @@
 
 mod app_constants;
 mod app_helpers;
+mod app_menu;
 mod app_runtime;
 mod app_types;
+mod backend_bridge_state;
 mod backend_config;
 mod backend_exit_state;
+mod backend_hooks;
 mod backend_http;
 mod backend_launch;
 mod backend_path;
@@ -18,16 +21,21 @@ mod desktop_bridge_commands;
 mod exit_cleanup;
 mod exit_events;
 mod exit_state;
+mod external_update;
 mod http_response;
+mod keybindings;
 mod launch_plan;
+mod log_window;
 mod logging;
 mod main_window;
 mod origin_policy;
 mod packaged_webui;
 mod process_control;
 mod restart_backend_flow;
+mod reveal;
 mod runtime_paths;
 mod shell_locale;
+mod staged_update;
 mod startup_loading;
 mod startup_mode;
 mod startup_task;
@@ -37,6 +45,8 @@ mod tray_labels;
 mod tray_menu_handler;
 mod tray_setup;
 mod ui_dispatch;
+mod update_channel;
+mod update_download;
 mod webui_paths;
 mod window_actions;
 
@@ -48,7 +58,10 @@ pub(crate) use app_helpers::{
 };
 pub(crate) use app_types::{
     AtomicFlagGuard, BackendBridgeResult, BackendBridgeState, BackendState,
-    DesktopAppUpdateCheckResult, LaunchPlan, RuntimeManifest, TrayMenuState,
+    DesktopAppUpdateCheckResult, LaunchPlan, ManualUpdateCheckState, PendingUpdateState,
+    RuntimeManifest, TrayMenuState, UpdateChannel, UpdateChannelState, UpdateDownloadFinishedPayload,
+    UpdateDownloadProgressPayload, UpdateDownloadStartedPayload, UpdateState,
+    UPDATE_DOWNLOAD_FINISHED_EVENT, UPDATE_DOWNLOAD_PROGRESS_EVENT, UPDATE_DOWNLOAD_STARTED_EVENT,
 };
 
 fn main() {
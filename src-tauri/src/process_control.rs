@@ -0,0 +1,79 @@
+use std::{
+    process::Child,
+    time::{Duration, Instant},
+};
+
+pub(crate) const BACKEND_STOP_TIMEOUT_MS_ENV: &str = "ASTRBOT_BACKEND_STOP_TIMEOUT_MS";
+const DEFAULT_STOP_TIMEOUT_MS: u64 = 8000;
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+fn stop_timeout() -> Duration {
+    std::env::var(BACKEND_STOP_TIMEOUT_MS_ENV)
+        .ok()
+        .and_then(|raw| raw.trim().parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::from_millis(DEFAULT_STOP_TIMEOUT_MS))
+}
+
+#[cfg(target_os = "windows")]
+fn request_polite_termination(child: &mut Child) {
+    use std::process::{Command, Stdio};
+
+    let _ = Command::new("taskkill")
+        .args(["/pid", &child.id().to_string(), "/t"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .stdin(Stdio::null())
+        .status();
+}
+
+#[cfg(not(target_os = "windows"))]
+fn request_polite_termination(child: &mut Child) {
+    unsafe {
+        libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn force_termination(child: &mut Child) {
+    use std::process::{Command, Stdio};
+
+    let _ = Command::new("taskkill")
+        .args(["/pid", &child.id().to_string(), "/t", "/f"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .stdin(Stdio::null())
+        .status();
+}
+
+#[cfg(not(target_os = "windows"))]
+fn force_termination(child: &mut Child) {
+    let _ = child.kill();
+}
+
+/// Stops `child` gracefully: requests termination (`SIGTERM` on Unix,
+/// `taskkill` without `/f` on Windows), then polls `try_wait` for up to
+/// [`BACKEND_STOP_TIMEOUT_MS_ENV`] (default 8s, overridable) before
+/// escalating to a hard kill. Gives the Python backend a chance to flush
+/// configs, sqlite, and plugin caches instead of losing mid-write state to
+/// an immediate `SIGKILL`/`taskkill /f`.
+pub(crate) fn stop_child_process(child: &mut Child) {
+    request_polite_termination(child);
+
+    let deadline = Instant::now() + stop_timeout();
+    loop {
+        match child.try_wait() {
+            Ok(Some(_status)) => return,
+            Ok(None) => {}
+            Err(_) => return,
+        }
+
+        if Instant::now() >= deadline {
+            break;
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+
+    force_termination(child);
+    let _ = child.wait();
+}
@@ -0,0 +1,51 @@
+use std::{
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::desktop_bridge_commands::sanitize_bundle_environment;
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn reveal_command(path: &Path) -> Command {
+    let mut command = Command::new("xdg-open");
+    command.arg(path);
+    sanitize_bundle_environment(&mut command);
+    command
+}
+
+#[cfg(target_os = "macos")]
+fn reveal_command(path: &Path) -> Command {
+    let mut command = Command::new("open");
+    command.arg(path);
+    command
+}
+
+#[cfg(target_os = "windows")]
+fn reveal_command(path: &Path) -> Command {
+    let mut command = Command::new("explorer");
+    command.arg(path);
+    command
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn reveal_command(_path: &Path) -> Command {
+    Command::new("true")
+}
+
+/// Opens `path` (a directory) in the platform's file manager: Finder on
+/// macOS, Explorer on Windows, and `xdg-open` on Linux, sanitized against
+/// the same detected AppImage/Flatpak/Snap bundle environment external
+/// openers in `desktop_bridge_commands.rs` are.
+pub fn reveal_path_in_file_manager(path: &Path) -> Result<(), String> {
+    if !path.exists() {
+        return Err(format!("Path does not exist: {}", path.display()));
+    }
+
+    reveal_command(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|error| format!("Failed to open file manager: {error}"))
+}
@@ -0,0 +1,78 @@
+use std::path::{Path, PathBuf};
+
+/// The packaged app's per-user data root (`~/.astrbot`), holding the backend
+/// install, its logs, and exported configs. `None` only if the OS can't
+/// resolve a home directory at all.
+pub(crate) fn default_packaged_root_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".astrbot"))
+}
+
+/// Runtime directories a bridge command is allowed to open/reveal paths
+/// under. Kept as an explicit allow-list (rather than trusting whatever
+/// absolute path the frontend sends) so a buggy or compromised renderer
+/// can't point the system opener/file manager at arbitrary locations.
+fn allowed_runtime_roots() -> Vec<PathBuf> {
+    default_packaged_root_dir().into_iter().collect()
+}
+
+/// True if `candidate` is already-canonicalized and falls inside one of
+/// `roots` (also already-canonicalized). Split out from
+/// [`canonicalize_within_allowed_roots`] so the prefix-matching logic can be
+/// unit-tested without touching the filesystem.
+fn is_within_roots(candidate: &Path, roots: &[PathBuf]) -> bool {
+    roots.iter().any(|root| candidate.starts_with(root))
+}
+
+/// Canonicalizes `path` and checks it falls inside an allowed runtime
+/// directory, so `desktop_bridge_open_path`/
+/// `desktop_bridge_reveal_in_file_manager` can't be used to open or reveal
+/// arbitrary filesystem locations.
+pub(crate) fn canonicalize_within_allowed_roots(path: &Path) -> Result<PathBuf, String> {
+    let canonical_path = path
+        .canonicalize()
+        .map_err(|error| format!("Failed to resolve path {}: {}", path.display(), error))?;
+
+    let canonical_roots: Vec<PathBuf> = allowed_runtime_roots()
+        .iter()
+        .filter_map(|root| root.canonicalize().ok())
+        .collect();
+
+    if !is_within_roots(&canonical_path, &canonical_roots) {
+        return Err(format!(
+            "Path {} is outside the allowed runtime directories.",
+            canonical_path.display()
+        ));
+    }
+
+    Ok(canonical_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_within_roots;
+    use std::path::PathBuf;
+
+    #[test]
+    fn accepts_a_path_nested_under_an_allowed_root() {
+        let roots = vec![PathBuf::from("/home/user/.astrbot")];
+        assert!(is_within_roots(
+            &PathBuf::from("/home/user/.astrbot/logs/desktop.log"),
+            &roots
+        ));
+    }
+
+    #[test]
+    fn rejects_a_path_outside_every_allowed_root() {
+        let roots = vec![PathBuf::from("/home/user/.astrbot")];
+        assert!(!is_within_roots(&PathBuf::from("/etc/passwd"), &roots));
+    }
+
+    #[test]
+    fn rejects_a_sibling_directory_with_a_shared_prefix() {
+        let roots = vec![PathBuf::from("/home/user/.astrbot")];
+        assert!(!is_within_roots(
+            &PathBuf::from("/home/user/.astrbot-evil/secrets"),
+            &roots
+        ));
+    }
+}
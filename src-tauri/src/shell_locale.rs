@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     env, fs,
     path::{Path, PathBuf},
 };
@@ -6,98 +7,353 @@ use std::{
 use serde_json::{Map, Value};
 
 const LOCALE_FIELD: &str = "locale";
+const AUTO_UPDATE_CHECK_FIELD: &str = "auto_update_check_enabled";
+const UPDATE_CHANNEL_FIELD: &str = "update_channel";
+const KEYBINDINGS_FIELD: &str = "keybindings";
+const LOCALES_DIR_NAME: &str = "locales";
 
 fn empty_state_object() -> Value {
     Value::Object(Map::new())
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ShellTexts {
-    pub tray_hide: &'static str,
-    pub tray_show: &'static str,
-    pub tray_reload: &'static str,
-    pub tray_restart_backend: &'static str,
-    pub tray_quit: &'static str,
+    pub tray_hide: String,
+    pub tray_show: String,
+    pub tray_reload: String,
+    pub tray_restart_backend: String,
+    pub tray_restarting_backend: String,
+    pub tray_cancel_update: String,
+    pub tray_check_update: String,
+    pub tray_checking_update: String,
+    pub tray_update_channel_stable: String,
+    pub tray_update_channel_beta: String,
+    pub tray_check_external_update: String,
+    pub tray_open_logs: String,
+    pub tray_open_log_folder: String,
+    pub tray_open_data_folder: String,
+    pub tray_quit: String,
+    pub tray_restart_backend_confirm: String,
+    pub tray_restart_backend_cancel: String,
+    pub tray_quit_confirm: String,
+    pub tray_quit_cancel: String,
 }
 
-pub fn shell_texts_for_locale(locale: &str) -> ShellTexts {
+fn default_shell_texts(locale: &str) -> ShellTexts {
     if locale == "en-US" {
         return ShellTexts {
-            tray_hide: "Hide AstrBot",
-            tray_show: "Show AstrBot",
-            tray_reload: "Reload",
-            tray_restart_backend: "Restart Backend",
-            tray_quit: "Quit",
+            tray_hide: "Hide AstrBot".to_string(),
+            tray_show: "Show AstrBot".to_string(),
+            tray_reload: "Reload".to_string(),
+            tray_restart_backend: "Restart Backend".to_string(),
+            tray_restarting_backend: "Restarting…".to_string(),
+            tray_cancel_update: "Cancel Update".to_string(),
+            tray_check_update: "Check for Updates".to_string(),
+            tray_checking_update: "Checking for Updates…".to_string(),
+            tray_update_channel_stable: "Update Channel: Stable".to_string(),
+            tray_update_channel_beta: "Update Channel: Beta".to_string(),
+            tray_check_external_update: "Apply Local Update".to_string(),
+            tray_open_logs: "View Logs".to_string(),
+            tray_open_log_folder: "Open Log Folder".to_string(),
+            tray_open_data_folder: "Open Data Folder".to_string(),
+            tray_quit: "Quit".to_string(),
+            tray_restart_backend_confirm: "Confirm Restart".to_string(),
+            tray_restart_backend_cancel: "Cancel".to_string(),
+            tray_quit_confirm: "Confirm Quit".to_string(),
+            tray_quit_cancel: "Cancel".to_string(),
         };
     }
 
-    ShellTexts {
-        tray_hide: "隐藏 AstrBot",
-        tray_show: "显示 AstrBot",
-        tray_reload: "重新加载",
-        tray_restart_backend: "重启后端",
-        tray_quit: "退出",
+    if locale == "zh-CN" {
+        return ShellTexts {
+            tray_hide: "隐藏 AstrBot".to_string(),
+            tray_show: "显示 AstrBot".to_string(),
+            tray_reload: "重新加载".to_string(),
+            tray_restart_backend: "重启后端".to_string(),
+            tray_restarting_backend: "重启中…".to_string(),
+            tray_cancel_update: "取消更新".to_string(),
+            tray_check_update: "检查更新".to_string(),
+            tray_checking_update: "正在检查更新…".to_string(),
+            tray_update_channel_stable: "更新渠道：稳定版".to_string(),
+            tray_update_channel_beta: "更新渠道：测试版".to_string(),
+            tray_check_external_update: "应用本地更新包".to_string(),
+            tray_open_logs: "查看日志".to_string(),
+            tray_open_log_folder: "打开日志文件夹".to_string(),
+            tray_open_data_folder: "打开数据文件夹".to_string(),
+            tray_quit: "退出".to_string(),
+            tray_restart_backend_confirm: "确认重启".to_string(),
+            tray_restart_backend_cancel: "取消".to_string(),
+            tray_quit_confirm: "确认退出".to_string(),
+            tray_quit_cancel: "取消".to_string(),
+        };
+    }
+
+    // Unknown locale with no catalog on disk: the compiled-in English copy
+    // is the universal fallback so the tray never ends up with blank labels.
+    default_shell_texts("en-US")
+}
+
+fn locales_dir_for(packaged_root_dir: Option<&Path>) -> Option<PathBuf> {
+    packaged_root_dir.map(|root| root.join(LOCALES_DIR_NAME))
+}
+
+fn locale_catalog_path(locale: &str, packaged_root_dir: Option<&Path>) -> Option<PathBuf> {
+    locales_dir_for(packaged_root_dir).map(|dir| dir.join(format!("{locale}.json")))
+}
+
+/// Loads the `label key -> text` overrides from `locales/<locale>.json` under
+/// the packaged root. Missing files, unreadable files, and non-string
+/// values are all treated as "no override for this key" rather than errors,
+/// so a malformed community translation can't take down the tray menu.
+fn load_locale_catalog(locale: &str, packaged_root_dir: Option<&Path>) -> HashMap<String, String> {
+    let Some(catalog_path) = locale_catalog_path(locale, packaged_root_dir) else {
+        return HashMap::new();
+    };
+
+    let Ok(raw) = fs::read_to_string(&catalog_path) else {
+        return HashMap::new();
+    };
+
+    let Ok(Value::Object(catalog)) = serde_json::from_str::<Value>(&raw) else {
+        crate::append_desktop_log(&format!(
+            "locale catalog {} is not a JSON object of labels; ignoring it",
+            catalog_path.display()
+        ));
+        return HashMap::new();
+    };
+
+    catalog
+        .into_iter()
+        .filter_map(|(key, value)| value.as_str().map(|text| (key, text.to_string())))
+        .collect()
+}
+
+fn apply_catalog_override(field: &mut String, key: &str, catalog: &HashMap<String, String>) {
+    if let Some(text) = catalog.get(key) {
+        *field = text.clone();
     }
 }
 
+/// Builds the label set for `locale`, starting from the compiled-in English
+/// or Simplified Chinese copy and then overlaying any matching keys found in
+/// `locales/<locale>.json`. This lets the community ship a brand-new
+/// language by dropping in a catalog file, or patch a handful of strings in
+/// an existing one, without recompiling the binary.
+pub fn shell_texts_for_locale(locale: &str, packaged_root_dir: Option<&Path>) -> ShellTexts {
+    let mut texts = default_shell_texts(locale);
+    let catalog = load_locale_catalog(locale, packaged_root_dir);
+    if catalog.is_empty() {
+        return texts;
+    }
+
+    apply_catalog_override(&mut texts.tray_hide, "tray_hide", &catalog);
+    apply_catalog_override(&mut texts.tray_show, "tray_show", &catalog);
+    apply_catalog_override(&mut texts.tray_reload, "tray_reload", &catalog);
+    apply_catalog_override(
+        &mut texts.tray_restart_backend,
+        "tray_restart_backend",
+        &catalog,
+    );
+    apply_catalog_override(
+        &mut texts.tray_restarting_backend,
+        "tray_restarting_backend",
+        &catalog,
+    );
+    apply_catalog_override(
+        &mut texts.tray_cancel_update,
+        "tray_cancel_update",
+        &catalog,
+    );
+    apply_catalog_override(&mut texts.tray_check_update, "tray_check_update", &catalog);
+    apply_catalog_override(
+        &mut texts.tray_checking_update,
+        "tray_checking_update",
+        &catalog,
+    );
+    apply_catalog_override(
+        &mut texts.tray_update_channel_stable,
+        "tray_update_channel_stable",
+        &catalog,
+    );
+    apply_catalog_override(
+        &mut texts.tray_update_channel_beta,
+        "tray_update_channel_beta",
+        &catalog,
+    );
+    apply_catalog_override(
+        &mut texts.tray_check_external_update,
+        "tray_check_external_update",
+        &catalog,
+    );
+    apply_catalog_override(&mut texts.tray_open_logs, "tray_open_logs", &catalog);
+    apply_catalog_override(
+        &mut texts.tray_open_log_folder,
+        "tray_open_log_folder",
+        &catalog,
+    );
+    apply_catalog_override(
+        &mut texts.tray_open_data_folder,
+        "tray_open_data_folder",
+        &catalog,
+    );
+    apply_catalog_override(&mut texts.tray_quit, "tray_quit", &catalog);
+    apply_catalog_override(
+        &mut texts.tray_restart_backend_confirm,
+        "tray_restart_backend_confirm",
+        &catalog,
+    );
+    apply_catalog_override(
+        &mut texts.tray_restart_backend_cancel,
+        "tray_restart_backend_cancel",
+        &catalog,
+    );
+    apply_catalog_override(&mut texts.tray_quit_confirm, "tray_quit_confirm", &catalog);
+    apply_catalog_override(&mut texts.tray_quit_cancel, "tray_quit_cancel", &catalog);
+
+    texts
+}
+
+/// Enumerates the locales the frontend can offer in a language picker: the
+/// two compiled-in locales plus every `locales/<tag>.json` file found under
+/// the packaged root, sorted and deduplicated.
+pub fn available_locale_catalogs(packaged_root_dir: Option<&Path>) -> Vec<String> {
+    let mut locales = vec!["en-US".to_string(), "zh-CN".to_string()];
+
+    if let Some(dir) = locales_dir_for(packaged_root_dir) {
+        if let Ok(entries) = fs::read_dir(&dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                    continue;
+                }
+                if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    locales.push(stem.to_string());
+                }
+            }
+        }
+    }
+
+    locales.sort();
+    locales.dedup();
+    locales
+}
+
 pub fn resolve_shell_locale(
     default_shell_locale: &'static str,
     packaged_root_dir: Option<PathBuf>,
-) -> &'static str {
+) -> String {
     if let Some(locale) = read_cached_shell_locale(packaged_root_dir.as_deref()) {
         return locale;
     }
 
     for env_key in ["ASTRBOT_DESKTOP_LOCALE", "LC_ALL", "LANG"] {
         if let Ok(value) = env::var(env_key) {
-            if let Some(locale) = normalize_shell_locale(&value) {
+            if let Some(locale) = normalize_shell_locale(&value, packaged_root_dir.as_deref()) {
                 return locale;
             }
         }
     }
 
-    default_shell_locale
+    default_shell_locale.to_string()
 }
 
-pub(crate) fn normalize_shell_locale(raw: &str) -> Option<&'static str> {
+/// Accepts `raw` as a valid shell locale if it's one of the compiled-in
+/// tags, a tag with a matching `locales/<tag>.json` catalog on disk, or a
+/// `zh`/`en` language prefix that we fold onto the closest compiled-in
+/// locale (e.g. `zh-TW` or `zh_Hans` both fall back to `zh-CN` copy).
+pub(crate) fn normalize_shell_locale(
+    raw: &str,
+    packaged_root_dir: Option<&Path>,
+) -> Option<String> {
     let raw = raw.trim();
     if raw.is_empty() {
         return None;
     }
     if raw == "zh-CN" {
-        return Some("zh-CN");
+        return Some("zh-CN".to_string());
     }
     if raw == "en-US" {
-        return Some("en-US");
+        return Some("en-US".to_string());
+    }
+
+    if let Some(catalog_path) = locale_catalog_path(raw, packaged_root_dir) {
+        if catalog_path.is_file() {
+            return Some(raw.to_string());
+        }
     }
 
     let lowered = raw.to_ascii_lowercase();
     if lowered.starts_with("zh") {
-        return Some("zh-CN");
+        return Some("zh-CN".to_string());
     }
     if lowered.starts_with("en") {
-        return Some("en-US");
+        return Some("en-US".to_string());
     }
     None
 }
 
-fn desktop_state_path_for_locale(packaged_root_dir: Option<&Path>) -> Option<PathBuf> {
+pub(crate) fn data_dir_for(packaged_root_dir: Option<&Path>) -> Option<PathBuf> {
     if let Ok(root) = env::var("ASTRBOT_ROOT") {
         let path = PathBuf::from(root.trim());
         if !path.as_os_str().is_empty() {
-            return Some(path.join("data").join("desktop_state.json"));
+            return Some(path.join("data"));
         }
     }
 
-    packaged_root_dir.map(|root| root.join("data").join("desktop_state.json"))
+    packaged_root_dir.map(|root| root.join("data"))
 }
 
-fn read_cached_shell_locale(packaged_root_dir: Option<&Path>) -> Option<&'static str> {
+fn desktop_state_path_for_locale(packaged_root_dir: Option<&Path>) -> Option<PathBuf> {
+    data_dir_for(packaged_root_dir).map(|data_dir| data_dir.join("desktop_state.json"))
+}
+
+fn read_cached_shell_locale(packaged_root_dir: Option<&Path>) -> Option<String> {
     let state_path = desktop_state_path_for_locale(packaged_root_dir)?;
     let raw = fs::read_to_string(state_path).ok()?;
     let parsed: serde_json::Value = serde_json::from_str(&raw).ok()?;
     let locale = parsed.get(LOCALE_FIELD)?.as_str()?;
-    normalize_shell_locale(locale)
+    normalize_shell_locale(locale, packaged_root_dir)
+}
+
+fn load_desktop_state_object(state_path: &Path, log_context: &str) -> Value {
+    match fs::read_to_string(state_path) {
+        Ok(raw) => match serde_json::from_str::<Value>(&raw) {
+            Ok(value) => value,
+            Err(error) => {
+                crate::append_desktop_log(&format!(
+                    "failed to parse {} state {}: {}. resetting state file",
+                    log_context,
+                    state_path.display(),
+                    error
+                ));
+                empty_state_object()
+            }
+        },
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => empty_state_object(),
+        Err(_) => empty_state_object(),
+    }
+}
+
+fn write_desktop_state_object(state_path: &Path, value: &Value) -> Result<(), String> {
+    if let Some(parent_dir) = state_path.parent() {
+        fs::create_dir_all(parent_dir).map_err(|error| {
+            format!(
+                "Failed to create desktop state directory {}: {}",
+                parent_dir.display(),
+                error
+            )
+        })?;
+    }
+
+    let serialized = serde_json::to_string_pretty(value)
+        .map_err(|error| format!("Failed to serialize desktop state: {error}"))?;
+    fs::write(state_path, serialized).map_err(|error| {
+        format!(
+            "Failed to write desktop state {}: {}",
+            state_path.display(),
+            error
+        )
+    })
 }
 
 fn ensure_object(value: &mut Value) -> &mut Map<String, Value> {
@@ -116,7 +372,7 @@ pub(crate) fn write_cached_shell_locale(
     locale: Option<&str>,
     packaged_root_dir: Option<&Path>,
 ) -> Result<(), String> {
-    let normalized_locale = locale.and_then(normalize_shell_locale);
+    let normalized_locale = locale.and_then(|raw| normalize_shell_locale(raw, packaged_root_dir));
     if let Some(raw_locale) = locale {
         if normalized_locale.is_none() {
             crate::append_desktop_log(&format!(
@@ -173,10 +429,7 @@ pub(crate) fn write_cached_shell_locale(
     let object = ensure_object(&mut parsed);
 
     if let Some(normalized_locale) = normalized_locale {
-        object.insert(
-            LOCALE_FIELD.to_string(),
-            Value::String(normalized_locale.to_string()),
-        );
+        object.insert(LOCALE_FIELD.to_string(), Value::String(normalized_locale));
     } else {
         object.remove(LOCALE_FIELD);
     }
@@ -194,28 +447,238 @@ pub(crate) fn write_cached_shell_locale(
     Ok(())
 }
 
+pub(crate) fn read_cached_auto_update_check_enabled(
+    packaged_root_dir: Option<&Path>,
+) -> Option<bool> {
+    let state_path = desktop_state_path_for_locale(packaged_root_dir)?;
+    let raw = fs::read_to_string(state_path).ok()?;
+    let parsed: Value = serde_json::from_str(&raw).ok()?;
+    parsed.get(AUTO_UPDATE_CHECK_FIELD)?.as_bool()
+}
+
+pub(crate) fn write_cached_auto_update_check_enabled(
+    enabled: bool,
+    packaged_root_dir: Option<&Path>,
+) -> Result<(), String> {
+    let Some(state_path) = desktop_state_path_for_locale(packaged_root_dir) else {
+        crate::append_desktop_log(
+            "desktop state path is unavailable; skipping auto-update-check persistence",
+        );
+        return Ok(());
+    };
+
+    let mut parsed = load_desktop_state_object(&state_path, "auto update check");
+    let object = ensure_object(&mut parsed);
+    object.insert(AUTO_UPDATE_CHECK_FIELD.to_string(), Value::Bool(enabled));
+
+    write_desktop_state_object(&state_path, &parsed)
+}
+
+pub(crate) fn read_cached_update_channel(
+    packaged_root_dir: Option<&Path>,
+) -> Option<crate::UpdateChannel> {
+    let state_path = desktop_state_path_for_locale(packaged_root_dir)?;
+    let raw = fs::read_to_string(state_path).ok()?;
+    let parsed: Value = serde_json::from_str(&raw).ok()?;
+    let channel = parsed.get(UPDATE_CHANNEL_FIELD)?.as_str()?;
+    crate::UpdateChannel::from_str(channel)
+}
+
+pub(crate) fn write_cached_update_channel(
+    channel: crate::UpdateChannel,
+    packaged_root_dir: Option<&Path>,
+) -> Result<(), String> {
+    let Some(state_path) = desktop_state_path_for_locale(packaged_root_dir) else {
+        crate::append_desktop_log(
+            "desktop state path is unavailable; skipping update channel persistence",
+        );
+        return Ok(());
+    };
+
+    let mut parsed = load_desktop_state_object(&state_path, "update channel");
+    let object = ensure_object(&mut parsed);
+    object.insert(
+        UPDATE_CHANNEL_FIELD.to_string(),
+        Value::String(channel.as_str().to_string()),
+    );
+
+    write_desktop_state_object(&state_path, &parsed)
+}
+
+/// Reads a user-supplied `keybindings` override map (action ID -> accelerator
+/// string) from `desktop_state.json`. Malformed or missing entries are
+/// dropped silently so a typo in one binding can't break the whole menu.
+pub(crate) fn read_cached_keybindings(
+    packaged_root_dir: Option<&Path>,
+) -> Option<HashMap<String, String>> {
+    let state_path = desktop_state_path_for_locale(packaged_root_dir)?;
+    let raw = fs::read_to_string(state_path).ok()?;
+    let parsed: Value = serde_json::from_str(&raw).ok()?;
+    let object = parsed.get(KEYBINDINGS_FIELD)?.as_object()?;
+
+    Some(
+        object
+            .iter()
+            .filter_map(|(action_id, accelerator)| {
+                accelerator
+                    .as_str()
+                    .map(|accelerator| (action_id.clone(), accelerator.to_string()))
+            })
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn shell_texts_for_locale_returns_english_copy() {
-        let texts = shell_texts_for_locale("en-US");
+        let texts = shell_texts_for_locale("en-US", None);
         assert_eq!(texts.tray_hide, "Hide AstrBot");
         assert_eq!(texts.tray_quit, "Quit");
     }
 
     #[test]
     fn shell_texts_for_locale_falls_back_to_zh_cn_copy() {
-        let texts = shell_texts_for_locale("zh-CN");
+        let texts = shell_texts_for_locale("zh-CN", None);
         assert_eq!(texts.tray_hide, "隐藏 AstrBot");
         assert_eq!(texts.tray_quit, "退出");
     }
 
     #[test]
     fn normalize_shell_locale_accepts_language_prefixes() {
-        assert_eq!(normalize_shell_locale("EN_us"), Some("en-US"));
-        assert_eq!(normalize_shell_locale("zh_TW"), Some("zh-CN"));
-        assert_eq!(normalize_shell_locale("fr-FR"), None);
+        assert_eq!(
+            normalize_shell_locale("EN_us", None),
+            Some("en-US".to_string())
+        );
+        assert_eq!(
+            normalize_shell_locale("zh_TW", None),
+            Some("zh-CN".to_string())
+        );
+        assert_eq!(normalize_shell_locale("fr-FR", None), None);
+    }
+
+    #[test]
+    fn shell_texts_for_locale_overlays_catalog_file_onto_default_copy() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "astrbot-desktop-test-{}-{}",
+            std::process::id(),
+            "locale_catalog_overlay"
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let locales_dir = temp_dir.join("locales");
+        fs::create_dir_all(&locales_dir).expect("create locales dir");
+        fs::write(
+            locales_dir.join("en-US.json"),
+            r#"{"tray_hide": "Tuck Away AstrBot", "tray_quit": 5}"#,
+        )
+        .expect("write locale catalog");
+
+        let texts = shell_texts_for_locale("en-US", Some(&temp_dir));
+        assert_eq!(texts.tray_hide, "Tuck Away AstrBot");
+        assert_eq!(texts.tray_quit, "Quit");
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn normalize_shell_locale_accepts_a_tag_with_a_catalog_on_disk() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "astrbot-desktop-test-{}-{}",
+            std::process::id(),
+            "normalize_custom_catalog"
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let locales_dir = temp_dir.join("locales");
+        fs::create_dir_all(&locales_dir).expect("create locales dir");
+        fs::write(
+            locales_dir.join("fr-FR.json"),
+            r#"{"tray_quit": "Quitter"}"#,
+        )
+        .expect("write locale catalog");
+
+        assert_eq!(
+            normalize_shell_locale("fr-FR", Some(&temp_dir)),
+            Some("fr-FR".to_string())
+        );
+        assert_eq!(normalize_shell_locale("de-DE", Some(&temp_dir)), None);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn available_locale_catalogs_includes_builtins_and_disk_catalogs() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "astrbot-desktop-test-{}-{}",
+            std::process::id(),
+            "available_locale_catalogs"
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let locales_dir = temp_dir.join("locales");
+        fs::create_dir_all(&locales_dir).expect("create locales dir");
+        fs::write(
+            locales_dir.join("fr-FR.json"),
+            r#"{"tray_quit": "Quitter"}"#,
+        )
+        .expect("write locale catalog");
+        fs::write(locales_dir.join("notes.txt"), "not a catalog").expect("write stray file");
+
+        let locales = available_locale_catalogs(Some(&temp_dir));
+        assert_eq!(locales, vec!["en-US", "fr-FR", "zh-CN"]);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn update_channel_round_trips_through_cached_state() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "astrbot-desktop-test-{}-{}",
+            std::process::id(),
+            "update_channel_round_trip"
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(&temp_dir).expect("create temp root dir");
+
+        assert_eq!(read_cached_update_channel(Some(&temp_dir)), None);
+
+        write_cached_update_channel(crate::UpdateChannel::Beta, Some(&temp_dir))
+            .expect("write update channel");
+        assert_eq!(
+            read_cached_update_channel(Some(&temp_dir)),
+            Some(crate::UpdateChannel::Beta)
+        );
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn read_cached_keybindings_ignores_non_string_entries() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "astrbot-desktop-test-{}-{}",
+            std::process::id(),
+            "keybindings_round_trip"
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(&temp_dir).expect("create temp root dir");
+
+        assert_eq!(read_cached_keybindings(Some(&temp_dir)), None);
+
+        let state_path = temp_dir.join("data").join("desktop_state.json");
+        fs::create_dir_all(state_path.parent().unwrap()).expect("create data dir");
+        fs::write(
+            &state_path,
+            r#"{"keybindings": {"tray_reload_window": "CmdOrCtrl+Alt+R", "tray_quit": 5}}"#,
+        )
+        .expect("write desktop state");
+
+        let keybindings = read_cached_keybindings(Some(&temp_dir)).expect("keybindings present");
+        assert_eq!(
+            keybindings.get("tray_reload_window").map(String::as_str),
+            Some("CmdOrCtrl+Alt+R")
+        );
+        assert!(!keybindings.contains_key("tray_quit"));
+
+        let _ = fs::remove_dir_all(&temp_dir);
     }
 }
@@ -0,0 +1,152 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::{append_desktop_log, append_shutdown_log, update_channel, UpdateChannelState};
+
+const STAGED_UPDATE_DIR: &str = "updates";
+const STAGED_UPDATE_ARCHIVE_FILE: &str = "staged-update.bin";
+const STAGED_UPDATE_METADATA_FILE: &str = "staged-update.json";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StagedUpdateMetadata {
+    version: String,
+}
+
+fn staged_update_archive_path(packaged_root_dir: Option<&Path>) -> Option<PathBuf> {
+    packaged_root_dir.map(|root| {
+        root.join(STAGED_UPDATE_DIR)
+            .join(STAGED_UPDATE_ARCHIVE_FILE)
+    })
+}
+
+fn staged_update_metadata_path(packaged_root_dir: Option<&Path>) -> Option<PathBuf> {
+    packaged_root_dir.map(|root| {
+        root.join(STAGED_UPDATE_DIR)
+            .join(STAGED_UPDATE_METADATA_FILE)
+    })
+}
+
+/// The version of a staged "install on quit" update, if one is present on
+/// disk and its archive file is actually there (a metadata file whose
+/// archive is missing doesn't count; the archive's size and contents are
+/// not checked).
+pub(crate) fn staged_update_version(packaged_root_dir: Option<&Path>) -> Option<String> {
+    let metadata_path = staged_update_metadata_path(packaged_root_dir)?;
+    let archive_path = staged_update_archive_path(packaged_root_dir)?;
+    if !archive_path.is_file() {
+        return None;
+    }
+
+    let raw = fs::read_to_string(metadata_path).ok()?;
+    let metadata: StagedUpdateMetadata = serde_json::from_str(&raw).ok()?;
+    Some(metadata.version)
+}
+
+/// Writes the downloaded update archive and its version to disk so the
+/// install can survive a force-kill between now and the next graceful
+/// quit, instead of only living in an in-memory `Mutex`.
+pub(crate) fn write_staged_update(
+    packaged_root_dir: Option<&Path>,
+    version: &str,
+    bytes: &[u8],
+) -> Result<(), String> {
+    let archive_path = staged_update_archive_path(packaged_root_dir).ok_or_else(|| {
+        "No packaged root directory available to stage the update in.".to_string()
+    })?;
+    let metadata_path = staged_update_metadata_path(packaged_root_dir).ok_or_else(|| {
+        "No packaged root directory available to stage the update in.".to_string()
+    })?;
+
+    if let Some(parent) = archive_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+    }
+
+    fs::write(&archive_path, bytes)
+        .map_err(|error| format!("Failed to write {}: {error}", archive_path.display()))?;
+
+    let metadata = StagedUpdateMetadata {
+        version: version.to_string(),
+    };
+    let serialized = serde_json::to_string(&metadata)
+        .map_err(|error| format!("Failed to serialize staged update metadata: {error}"))?;
+    fs::write(&metadata_path, serialized)
+        .map_err(|error| format!("Failed to write {}: {error}", metadata_path.display()))?;
+
+    Ok(())
+}
+
+/// Removes the staged archive and its metadata, regardless of whether the
+/// install that follows succeeded, so a failed or stale staged update is
+/// never retried silently.
+fn clear_staged_update(packaged_root_dir: Option<&Path>) {
+    if let Some(archive_path) = staged_update_archive_path(packaged_root_dir) {
+        let _ = fs::remove_file(archive_path);
+    }
+    if let Some(metadata_path) = staged_update_metadata_path(packaged_root_dir) {
+        let _ = fs::remove_file(metadata_path);
+    }
+}
+
+/// Installs a staged "install on quit" update if one is present, called
+/// from [`crate::exit_events::handle_exit_requested`] right before the
+/// desktop process actually exits. Re-checks for the update first (mirrors
+/// [`crate::desktop_bridge_commands::desktop_bridge_install_pending_update`])
+/// so a staged archive that's since been superseded by a newer release
+/// isn't installed over it; either way the staged files are cleared
+/// afterwards so a failed install doesn't retry forever.
+pub(crate) fn install_staged_update_if_present(app_handle: &AppHandle) {
+    let packaged_root_dir = crate::runtime_paths::default_packaged_root_dir();
+    let Some(staged_version) = staged_update_version(packaged_root_dir.as_deref()) else {
+        return;
+    };
+
+    let channel = app_handle
+        .try_state::<UpdateChannelState>()
+        .map(|state| state.current())
+        .unwrap_or_default();
+
+    let install_result = tauri::async_runtime::block_on(async {
+        let updater = update_channel::build_updater_for_channel(app_handle, channel)
+            .map_err(|error| format!("Failed to initialize updater: {error}"))?;
+        let update = updater
+            .check()
+            .await
+            .map_err(|error| format!("Failed to check desktop app update: {error}"))?
+            .ok_or_else(|| "No update available; discarding stale staged install.".to_string())?;
+
+        let latest_version = update.version.to_string();
+        if latest_version != staged_version {
+            return Err(format!(
+                "Staged update {staged_version} no longer matches the latest version {latest_version}; discarding."
+            ));
+        }
+
+        let archive_path =
+            staged_update_archive_path(packaged_root_dir.as_deref()).ok_or_else(|| {
+                "No packaged root directory available to read the staged update from.".to_string()
+            })?;
+        let bytes = fs::read(&archive_path)
+            .map_err(|error| format!("Failed to read {}: {error}", archive_path.display()))?;
+
+        update
+            .install(&bytes)
+            .map_err(|error| format!("Failed to install desktop app update: {error}"))
+    });
+
+    clear_staged_update(packaged_root_dir.as_deref());
+
+    match install_result {
+        Ok(()) => append_shutdown_log(&format!(
+            "installed staged desktop app update {staged_version} on quit"
+        )),
+        Err(error) => append_desktop_log(&format!(
+            "failed to install staged desktop app update {staged_version} on quit: {error}"
+        )),
+    }
+}
@@ -2,24 +2,58 @@ pub const TRAY_MENU_TOGGLE_WINDOW: &str = "tray_toggle_window";
 pub const TRAY_MENU_RELOAD_WINDOW: &str = "tray_reload_window";
 pub const TRAY_MENU_RESTART_BACKEND: &str = "tray_restart_backend";
 pub const TRAY_MENU_TOGGLE_AUTO_UPDATE_CHECK: &str = "tray_toggle_auto_update_check";
+pub const TRAY_MENU_CANCEL_UPDATE: &str = "tray_cancel_update";
+pub const TRAY_MENU_CHECK_UPDATE: &str = "tray_check_update";
+pub const TRAY_MENU_CYCLE_UPDATE_CHANNEL: &str = "tray_cycle_update_channel";
+pub const TRAY_MENU_CHECK_EXTERNAL_UPDATE: &str = "tray_check_external_update";
+pub const TRAY_MENU_OPEN_LOGS: &str = "tray_open_logs";
+pub const TRAY_MENU_OPEN_LOG_FOLDER: &str = "tray_open_log_folder";
+pub const TRAY_MENU_OPEN_DATA_FOLDER: &str = "tray_open_data_folder";
 pub const TRAY_MENU_QUIT: &str = "tray_quit";
+pub const TRAY_MENU_RESTART_BACKEND_CONFIRM: &str = "tray_restart_backend_confirm";
+pub const TRAY_MENU_RESTART_BACKEND_CANCEL: &str = "tray_restart_backend_cancel";
+pub const TRAY_MENU_QUIT_CONFIRM: &str = "tray_quit_confirm";
+pub const TRAY_MENU_QUIT_CANCEL: &str = "tray_quit_cancel";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TrayMenuAction {
     ToggleWindow,
     ReloadWindow,
-    RestartBackend,
     ToggleAutoUpdateCheck,
-    Quit,
+    CancelUpdate,
+    CheckForUpdate,
+    CycleUpdateChannel,
+    CheckExternalUpdate,
+    OpenLogs,
+    OpenLogFolder,
+    OpenDataFolder,
+    RestartBackendConfirm,
+    RestartBackendCancel,
+    QuitConfirm,
+    QuitCancel,
 }
 
+/// `TRAY_MENU_RESTART_BACKEND` and `TRAY_MENU_QUIT` are submenu *header* ids
+/// (see `tray_setup.rs`), not clickable items — `muda`/`tauri` never emit a
+/// menu-click event for a submenu header, so they never reach this match and
+/// intentionally have no corresponding [`TrayMenuAction`] variant. Only their
+/// child `_CONFIRM`/`_CANCEL` items are real actions.
 pub fn action_from_menu_id(menu_id: &str) -> Option<TrayMenuAction> {
     match menu_id {
         TRAY_MENU_TOGGLE_WINDOW => Some(TrayMenuAction::ToggleWindow),
         TRAY_MENU_RELOAD_WINDOW => Some(TrayMenuAction::ReloadWindow),
-        TRAY_MENU_RESTART_BACKEND => Some(TrayMenuAction::RestartBackend),
         TRAY_MENU_TOGGLE_AUTO_UPDATE_CHECK => Some(TrayMenuAction::ToggleAutoUpdateCheck),
-        TRAY_MENU_QUIT => Some(TrayMenuAction::Quit),
+        TRAY_MENU_CANCEL_UPDATE => Some(TrayMenuAction::CancelUpdate),
+        TRAY_MENU_CHECK_UPDATE => Some(TrayMenuAction::CheckForUpdate),
+        TRAY_MENU_CYCLE_UPDATE_CHANNEL => Some(TrayMenuAction::CycleUpdateChannel),
+        TRAY_MENU_CHECK_EXTERNAL_UPDATE => Some(TrayMenuAction::CheckExternalUpdate),
+        TRAY_MENU_OPEN_LOGS => Some(TrayMenuAction::OpenLogs),
+        TRAY_MENU_OPEN_LOG_FOLDER => Some(TrayMenuAction::OpenLogFolder),
+        TRAY_MENU_OPEN_DATA_FOLDER => Some(TrayMenuAction::OpenDataFolder),
+        TRAY_MENU_RESTART_BACKEND_CONFIRM => Some(TrayMenuAction::RestartBackendConfirm),
+        TRAY_MENU_RESTART_BACKEND_CANCEL => Some(TrayMenuAction::RestartBackendCancel),
+        TRAY_MENU_QUIT_CONFIRM => Some(TrayMenuAction::QuitConfirm),
+        TRAY_MENU_QUIT_CANCEL => Some(TrayMenuAction::QuitCancel),
         _ => None,
     }
 }
@@ -38,17 +72,53 @@ mod tests {
             action_from_menu_id(TRAY_MENU_RELOAD_WINDOW),
             Some(TrayMenuAction::ReloadWindow)
         );
-        assert_eq!(
-            action_from_menu_id(TRAY_MENU_RESTART_BACKEND),
-            Some(TrayMenuAction::RestartBackend)
-        );
         assert_eq!(
             action_from_menu_id(TRAY_MENU_TOGGLE_AUTO_UPDATE_CHECK),
             Some(TrayMenuAction::ToggleAutoUpdateCheck)
         );
         assert_eq!(
-            action_from_menu_id(TRAY_MENU_QUIT),
-            Some(TrayMenuAction::Quit)
+            action_from_menu_id(TRAY_MENU_CANCEL_UPDATE),
+            Some(TrayMenuAction::CancelUpdate)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_CHECK_UPDATE),
+            Some(TrayMenuAction::CheckForUpdate)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_CYCLE_UPDATE_CHANNEL),
+            Some(TrayMenuAction::CycleUpdateChannel)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_CHECK_EXTERNAL_UPDATE),
+            Some(TrayMenuAction::CheckExternalUpdate)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_OPEN_LOGS),
+            Some(TrayMenuAction::OpenLogs)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_OPEN_LOG_FOLDER),
+            Some(TrayMenuAction::OpenLogFolder)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_OPEN_DATA_FOLDER),
+            Some(TrayMenuAction::OpenDataFolder)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_RESTART_BACKEND_CONFIRM),
+            Some(TrayMenuAction::RestartBackendConfirm)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_RESTART_BACKEND_CANCEL),
+            Some(TrayMenuAction::RestartBackendCancel)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_QUIT_CONFIRM),
+            Some(TrayMenuAction::QuitConfirm)
+        );
+        assert_eq!(
+            action_from_menu_id(TRAY_MENU_QUIT_CANCEL),
+            Some(TrayMenuAction::QuitCancel)
         );
     }
 
@@ -56,4 +126,10 @@ mod tests {
     fn action_from_menu_id_returns_none_for_unknown_menu_id() {
         assert_eq!(action_from_menu_id("unknown-menu"), None);
     }
+
+    #[test]
+    fn action_from_menu_id_returns_none_for_submenu_headers() {
+        assert_eq!(action_from_menu_id(TRAY_MENU_RESTART_BACKEND), None);
+        assert_eq!(action_from_menu_id(TRAY_MENU_QUIT), None);
+    }
 }
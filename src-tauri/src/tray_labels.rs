@@ -1,6 +1,10 @@
+use std::sync::atomic::Ordering;
+
 use tauri::{menu::MenuItem, AppHandle, Manager};
 
-use crate::{runtime_paths, shell_locale, tray_actions, TrayMenuState};
+use crate::{
+    runtime_paths, shell_locale, tray_actions, BackendState, TrayMenuState, UpdateChannel,
+};
 
 fn set_menu_text_safe<F>(item: &MenuItem<tauri::Wry>, text: &str, item_name: &str, log: F)
 where
@@ -14,6 +18,18 @@ where
     }
 }
 
+fn set_menu_enabled_safe<F>(item: &MenuItem<tauri::Wry>, enabled: bool, item_name: &str, log: F)
+where
+    F: Fn(&str),
+{
+    if let Err(error) = item.set_enabled(enabled) {
+        log(&format!(
+            "failed to update tray menu enabled state for {}: {}",
+            item_name, error
+        ));
+    }
+}
+
 pub fn update_tray_menu_labels<F>(
     app_handle: &AppHandle,
     default_shell_locale: &'static str,
@@ -36,11 +52,10 @@ pub fn update_tray_menu_labels_with_visibility<F>(
         return;
     };
 
-    let locale = shell_locale::resolve_shell_locale(
-        default_shell_locale,
-        runtime_paths::default_packaged_root_dir(),
-    );
-    let shell_texts = shell_locale::shell_texts_for_locale(locale);
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    let locale =
+        shell_locale::resolve_shell_locale(default_shell_locale, packaged_root_dir.clone());
+    let shell_texts = shell_locale::shell_texts_for_locale(&locale, packaged_root_dir.as_deref());
     let effective_visible = if let Some(visible) = visible_override {
         visible
     } else {
@@ -51,9 +66,9 @@ pub fn update_tray_menu_labels_with_visibility<F>(
     };
 
     let toggle_label = if effective_visible {
-        shell_texts.tray_hide
+        &shell_texts.tray_hide
     } else {
-        shell_texts.tray_show
+        &shell_texts.tray_show
     };
 
     set_menu_text_safe(
@@ -64,20 +79,174 @@ pub fn update_tray_menu_labels_with_visibility<F>(
     );
     set_menu_text_safe(
         &tray_state.reload_item,
-        shell_texts.tray_reload,
+        &shell_texts.tray_reload,
         tray_actions::TRAY_MENU_RELOAD_WINDOW,
         &log,
     );
     set_menu_text_safe(
         &tray_state.restart_backend_item,
-        shell_texts.tray_restart_backend,
-        tray_actions::TRAY_MENU_RESTART_BACKEND,
+        &shell_texts.tray_restart_backend_confirm,
+        tray_actions::TRAY_MENU_RESTART_BACKEND_CONFIRM,
+        &log,
+    );
+    set_menu_text_safe(
+        &tray_state.check_external_update_item,
+        &shell_texts.tray_check_external_update,
+        tray_actions::TRAY_MENU_CHECK_EXTERNAL_UPDATE,
+        &log,
+    );
+    set_menu_text_safe(
+        &tray_state.open_logs_item,
+        &shell_texts.tray_open_logs,
+        tray_actions::TRAY_MENU_OPEN_LOGS,
         &log,
     );
     set_menu_text_safe(
         &tray_state.quit_item,
-        shell_texts.tray_quit,
-        tray_actions::TRAY_MENU_QUIT,
+        &shell_texts.tray_quit_confirm,
+        tray_actions::TRAY_MENU_QUIT_CONFIRM,
         &log,
     );
 }
+
+pub fn set_cancel_update_enabled<F>(app_handle: &AppHandle, enabled: bool, log: F)
+where
+    F: Fn(&str),
+{
+    let Some(tray_state) = app_handle.try_state::<TrayMenuState>() else {
+        return;
+    };
+
+    if let Err(error) = tray_state.cancel_update_item.set_enabled(enabled) {
+        log(&format!(
+            "failed to update tray menu enabled state for {}: {}",
+            tray_actions::TRAY_MENU_CANCEL_UPDATE,
+            error
+        ));
+    }
+}
+
+pub fn set_update_channel_label<F>(
+    app_handle: &AppHandle,
+    default_shell_locale: &'static str,
+    channel: UpdateChannel,
+    log: F,
+) where
+    F: Fn(&str),
+{
+    let Some(tray_state) = app_handle.try_state::<TrayMenuState>() else {
+        return;
+    };
+
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    let locale =
+        shell_locale::resolve_shell_locale(default_shell_locale, packaged_root_dir.clone());
+    let shell_texts = shell_locale::shell_texts_for_locale(&locale, packaged_root_dir.as_deref());
+    let label = match channel {
+        UpdateChannel::Stable => &shell_texts.tray_update_channel_stable,
+        UpdateChannel::Beta => &shell_texts.tray_update_channel_beta,
+    };
+
+    set_menu_text_safe(
+        &tray_state.update_channel_item,
+        label,
+        tray_actions::TRAY_MENU_CYCLE_UPDATE_CHANNEL,
+        &log,
+    );
+}
+
+pub fn set_check_update_running<F>(
+    app_handle: &AppHandle,
+    default_shell_locale: &'static str,
+    running: bool,
+    log: F,
+) where
+    F: Fn(&str),
+{
+    let Some(tray_state) = app_handle.try_state::<TrayMenuState>() else {
+        return;
+    };
+
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    let locale =
+        shell_locale::resolve_shell_locale(default_shell_locale, packaged_root_dir.clone());
+    let shell_texts = shell_locale::shell_texts_for_locale(&locale, packaged_root_dir.as_deref());
+    let label = if running {
+        &shell_texts.tray_checking_update
+    } else {
+        &shell_texts.tray_check_update
+    };
+
+    set_menu_text_safe(
+        &tray_state.check_update_item,
+        label,
+        tray_actions::TRAY_MENU_CHECK_UPDATE,
+        &log,
+    );
+    if let Err(error) = tray_state.check_update_item.set_enabled(!running) {
+        log(&format!(
+            "failed to update tray menu enabled state for {}: {}",
+            tray_actions::TRAY_MENU_CHECK_UPDATE,
+            error
+        ));
+    }
+}
+
+/// Greys out the actions that would immediately bounce against a backend
+/// that's already spawning or restarting, and relabels the restart submenu's
+/// confirm item to "Restarting…" while it's in flight. Call this on tray open and from the
+/// spawn/restart code paths so the menu never offers an action that can't
+/// currently succeed.
+pub fn refresh_tray_menu_state<F>(
+    app_handle: &AppHandle,
+    default_shell_locale: &'static str,
+    log: F,
+) where
+    F: Fn(&str) + Copy,
+{
+    let Some(tray_state) = app_handle.try_state::<TrayMenuState>() else {
+        return;
+    };
+
+    let backend_busy = app_handle
+        .try_state::<BackendState>()
+        .map(|state| {
+            state.is_spawning.load(Ordering::Relaxed) || state.is_restarting.load(Ordering::Relaxed)
+        })
+        .unwrap_or(false);
+
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    let locale =
+        shell_locale::resolve_shell_locale(default_shell_locale, packaged_root_dir.clone());
+    let shell_texts = shell_locale::shell_texts_for_locale(&locale, packaged_root_dir.as_deref());
+    let restart_label = if backend_busy {
+        &shell_texts.tray_restarting_backend
+    } else {
+        &shell_texts.tray_restart_backend_confirm
+    };
+
+    set_menu_text_safe(
+        &tray_state.restart_backend_item,
+        restart_label,
+        tray_actions::TRAY_MENU_RESTART_BACKEND_CONFIRM,
+        log,
+    );
+    set_menu_enabled_safe(
+        &tray_state.restart_backend_item,
+        !backend_busy,
+        tray_actions::TRAY_MENU_RESTART_BACKEND_CONFIRM,
+        log,
+    );
+    set_menu_enabled_safe(
+        &tray_state.reload_item,
+        !backend_busy,
+        tray_actions::TRAY_MENU_RELOAD_WINDOW,
+        log,
+    );
+    set_menu_enabled_safe(
+        &tray_state.toggle_item,
+        !backend_busy,
+        tray_actions::TRAY_MENU_TOGGLE_WINDOW,
+        log,
+    );
+}
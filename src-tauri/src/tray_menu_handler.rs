@@ -1,10 +1,12 @@
 use tauri::{AppHandle, Manager};
+use tauri_plugin_dialog::DialogExt;
 
 use crate::{
-    append_desktop_log, append_restart_log, append_shutdown_log, restart_backend_flow,
-    runtime_paths, shell_locale, tray_actions, tray_bridge_event, tray_labels, ui_dispatch,
-    window_actions, AutoUpdateCheckState, BackendState, DEFAULT_SHELL_LOCALE,
-    TRAY_RESTART_BACKEND_EVENT,
+    append_desktop_log, append_restart_log, append_shutdown_log, backend_bridge_state,
+    external_update, restart_backend_flow, reveal, runtime_paths, shell_locale, tray_actions,
+    tray_bridge_event, tray_labels, ui_dispatch, update_channel, window_actions, AtomicFlagGuard,
+    AutoUpdateCheckState, BackendState, ManualUpdateCheckState, UpdateChannelState, UpdateState,
+    DEFAULT_SHELL_LOCALE, TRAY_RESTART_BACKEND_EVENT,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,6 +23,61 @@ fn decide_tray_restart(backend_action_in_progress: bool) -> TrayRestartDecision
     }
 }
 
+fn start_tray_backend_restart(app_handle: &AppHandle) {
+    let state = app_handle.state::<BackendState>();
+    match decide_tray_restart(restart_backend_flow::is_backend_action_in_progress(&state)) {
+        TrayRestartDecision::IgnoreBecauseBackendActionInProgress => {
+            append_restart_log("tray restart ignored: backend action already in progress");
+            return;
+        }
+        TrayRestartDecision::ProceedWithRestart => {}
+    }
+    append_restart_log("tray requested backend restart");
+    window_actions::show_main_window(app_handle, DEFAULT_SHELL_LOCALE, append_desktop_log);
+    tray_bridge_event::emit_tray_restart_backend_event(
+        app_handle,
+        TRAY_RESTART_BACKEND_EVENT,
+        append_restart_log,
+    );
+    tray_labels::refresh_tray_menu_state(app_handle, DEFAULT_SHELL_LOCALE, append_desktop_log);
+
+    let app_handle_cloned = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        let result =
+            restart_backend_flow::run_restart_backend_task(app_handle_cloned.clone(), None).await;
+        backend_bridge_state::emit_backend_state(&app_handle_cloned);
+        if result.ok {
+            append_restart_log("backend restarted from tray menu");
+            if let Err(error) = ui_dispatch::run_on_main_thread_dispatch(
+                &app_handle_cloned,
+                "reload main window after tray restart",
+                move |main_app| {
+                    window_actions::reload_main_window(main_app, append_desktop_log);
+                },
+            ) {
+                append_restart_log(&format!(
+                    "failed to schedule main window reload after tray restart: {error}"
+                ));
+            }
+        } else {
+            let reason = result.reason.unwrap_or_else(|| "unknown error".to_string());
+            append_restart_log(&format!("backend restart from tray menu failed: {reason}"));
+        }
+        tray_labels::refresh_tray_menu_state(
+            &app_handle_cloned,
+            DEFAULT_SHELL_LOCALE,
+            append_desktop_log,
+        );
+    });
+}
+
+fn quit_desktop_process(app_handle: &AppHandle) {
+    let state = app_handle.state::<BackendState>();
+    state.mark_quitting();
+    append_shutdown_log("tray quit requested, exiting desktop process");
+    app_handle.exit(0);
+}
+
 pub fn handle_tray_menu_event(app_handle: &AppHandle, menu_id: &str) {
     match tray_actions::action_from_menu_id(menu_id) {
         Some(tray_actions::TrayMenuAction::ToggleWindow) => {
@@ -29,46 +86,11 @@ pub fn handle_tray_menu_event(app_handle: &AppHandle, menu_id: &str) {
         Some(tray_actions::TrayMenuAction::ReloadWindow) => {
             window_actions::reload_main_window(app_handle, append_desktop_log)
         }
-        Some(tray_actions::TrayMenuAction::RestartBackend) => {
-            let state = app_handle.state::<BackendState>();
-            match decide_tray_restart(restart_backend_flow::is_backend_action_in_progress(&state)) {
-                TrayRestartDecision::IgnoreBecauseBackendActionInProgress => {
-                    append_restart_log("tray restart ignored: backend action already in progress");
-                    return;
-                }
-                TrayRestartDecision::ProceedWithRestart => {}
-            }
-            append_restart_log("tray requested backend restart");
-            window_actions::show_main_window(app_handle, DEFAULT_SHELL_LOCALE, append_desktop_log);
-            tray_bridge_event::emit_tray_restart_backend_event(
-                app_handle,
-                TRAY_RESTART_BACKEND_EVENT,
-                append_restart_log,
-            );
-
-            let app_handle_cloned = app_handle.clone();
-            tauri::async_runtime::spawn(async move {
-                let result =
-                    restart_backend_flow::run_restart_backend_task(app_handle_cloned.clone(), None)
-                        .await;
-                if result.ok {
-                    append_restart_log("backend restarted from tray menu");
-                    if let Err(error) = ui_dispatch::run_on_main_thread_dispatch(
-                        &app_handle_cloned,
-                        "reload main window after tray restart",
-                        move |main_app| {
-                            window_actions::reload_main_window(main_app, append_desktop_log);
-                        },
-                    ) {
-                        append_restart_log(&format!(
-                            "failed to schedule main window reload after tray restart: {error}"
-                        ));
-                    }
-                } else {
-                    let reason = result.reason.unwrap_or_else(|| "unknown error".to_string());
-                    append_restart_log(&format!("backend restart from tray menu failed: {reason}"));
-                }
-            });
+        Some(tray_actions::TrayMenuAction::RestartBackendConfirm) => {
+            start_tray_backend_restart(app_handle);
+        }
+        Some(tray_actions::TrayMenuAction::RestartBackendCancel) => {
+            append_restart_log("tray restart submenu: cancelled");
         }
         Some(tray_actions::TrayMenuAction::ToggleAutoUpdateCheck) => {
             let auto_update_state = app_handle.state::<AutoUpdateCheckState>();
@@ -96,11 +118,193 @@ pub fn handle_tray_menu_event(app_handle: &AppHandle, menu_id: &str) {
                 append_desktop_log,
             );
         }
-        Some(tray_actions::TrayMenuAction::Quit) => {
-            let state = app_handle.state::<BackendState>();
-            state.mark_quitting();
-            append_shutdown_log("tray quit requested, exiting desktop process");
-            app_handle.exit(0);
+        Some(tray_actions::TrayMenuAction::CancelUpdate) => {
+            let update_state = app_handle.state::<UpdateState>();
+            if update_state.request_cancel() {
+                append_desktop_log(
+                    "tray requested cancellation of the in-progress update download",
+                );
+            }
+        }
+        Some(tray_actions::TrayMenuAction::CycleUpdateChannel) => {
+            let update_channel_state = app_handle.state::<UpdateChannelState>();
+            let channel = update_channel_state.cycle();
+            let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+            if let Err(error) =
+                shell_locale::write_cached_update_channel(channel, packaged_root_dir.as_deref())
+            {
+                append_desktop_log(&format!(
+                    "failed to persist update channel setting: {error}"
+                ));
+            } else {
+                append_desktop_log(&format!(
+                    "tray switched update channel to {}",
+                    channel.as_str()
+                ));
+            }
+            tray_labels::set_update_channel_label(
+                app_handle,
+                DEFAULT_SHELL_LOCALE,
+                channel,
+                append_desktop_log,
+            );
+        }
+        Some(tray_actions::TrayMenuAction::CheckForUpdate) => {
+            let app_handle_cloned = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                let check_state = app_handle_cloned.state::<ManualUpdateCheckState>();
+                let Some(_guard) = AtomicFlagGuard::try_set(&check_state.in_progress) else {
+                    append_desktop_log("check-for-updates ignored: a check is already in progress");
+                    return;
+                };
+
+                tray_labels::set_check_update_running(
+                    &app_handle_cloned,
+                    DEFAULT_SHELL_LOCALE,
+                    true,
+                    append_desktop_log,
+                );
+
+                let dialog = app_handle_cloned.dialog();
+                let update_channel = app_handle_cloned
+                    .try_state::<UpdateChannelState>()
+                    .map(|state| state.current())
+                    .unwrap_or_default();
+                match update_channel::build_updater_for_channel(&app_handle_cloned, update_channel)
+                {
+                    Ok(updater) => match updater.check().await {
+                        Ok(Some(update)) => {
+                            let new_version = update.version.to_string();
+                            append_desktop_log(&format!(
+                                "manual update check found new version {new_version}"
+                            ));
+                            dialog
+                                .message(format!(
+                                    "发现新版本 {new_version}，请通过托盘菜单或重启应用完成安装。"
+                                ))
+                                .title("发现新版本")
+                                .kind(tauri_plugin_dialog::MessageDialogKind::Info)
+                                .blocking_show();
+                        }
+                        Ok(None) => {
+                            let current_version =
+                                app_handle_cloned.package_info().version.to_string();
+                            append_desktop_log(
+                                "manual update check: already on the latest version",
+                            );
+                            dialog
+                                .message(format!("当前已是最新版本 {current_version}。"))
+                                .title("检查更新")
+                                .kind(tauri_plugin_dialog::MessageDialogKind::Info)
+                                .blocking_show();
+                        }
+                        Err(error) => {
+                            append_desktop_log(&format!("manual update check failed: {error}"));
+                            dialog
+                                .message(format!("检查更新失败：{error}"))
+                                .title("检查更新失败")
+                                .kind(tauri_plugin_dialog::MessageDialogKind::Error)
+                                .blocking_show();
+                        }
+                    },
+                    Err(error) => {
+                        append_desktop_log(&format!(
+                            "manual update check: failed to init updater: {error}"
+                        ));
+                        dialog
+                            .message(format!("初始化更新器失败：{error}"))
+                            .title("检查更新失败")
+                            .kind(tauri_plugin_dialog::MessageDialogKind::Error)
+                            .blocking_show();
+                    }
+                }
+
+                tray_labels::set_check_update_running(
+                    &app_handle_cloned,
+                    DEFAULT_SHELL_LOCALE,
+                    false,
+                    append_desktop_log,
+                );
+            });
+        }
+        Some(tray_actions::TrayMenuAction::CheckExternalUpdate) => {
+            let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+            let current_version = app_handle.package_info().version.to_string();
+            let dialog = app_handle.dialog();
+
+            match external_update::find_pending_external_update(
+                packaged_root_dir.as_deref(),
+                &current_version,
+            ) {
+                Some(plan) => {
+                    let should_install = dialog
+                        .message(format!(
+                            "发现本地离线更新包 {}，是否立即安装并重启应用？",
+                            plan.version
+                        ))
+                        .title("发现本地更新")
+                        .kind(tauri_plugin_dialog::MessageDialogKind::Info)
+                        .buttons(tauri_plugin_dialog::MessageDialogButtons::YesNo)
+                        .blocking_show();
+
+                    if !should_install {
+                        append_desktop_log("user declined to install the local external update");
+                        return;
+                    }
+
+                    if let Err(error) = external_update::install_external_update(app_handle, &plan)
+                    {
+                        append_desktop_log(&format!("failed to install external update: {error}"));
+                        dialog
+                            .message(format!("安装本地更新失败：{error}"))
+                            .title("安装失败")
+                            .kind(tauri_plugin_dialog::MessageDialogKind::Error)
+                            .blocking_show();
+                    }
+                }
+                None => {
+                    dialog
+                        .message("未找到有效的本地更新包。")
+                        .title("检查本地更新")
+                        .kind(tauri_plugin_dialog::MessageDialogKind::Info)
+                        .blocking_show();
+                }
+            }
+        }
+        Some(tray_actions::TrayMenuAction::OpenLogs) => {
+            window_actions::show_log_window(app_handle, append_desktop_log);
+        }
+        Some(tray_actions::TrayMenuAction::OpenLogFolder) => {
+            let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+            let log_path = crate::logging::resolve_desktop_log_path(
+                packaged_root_dir,
+                crate::DESKTOP_LOG_FILE,
+            );
+            match log_path.parent() {
+                Some(log_dir) => {
+                    if let Err(error) = reveal::reveal_path_in_file_manager(log_dir) {
+                        append_desktop_log(&format!("failed to open log folder: {error}"));
+                    }
+                }
+                None => append_desktop_log("failed to open log folder: no parent directory"),
+            }
+        }
+        Some(tray_actions::TrayMenuAction::OpenDataFolder) => {
+            let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+            match shell_locale::data_dir_for(packaged_root_dir.as_deref()) {
+                Some(data_dir) => {
+                    if let Err(error) = reveal::reveal_path_in_file_manager(&data_dir) {
+                        append_desktop_log(&format!("failed to open data folder: {error}"));
+                    }
+                }
+                None => append_desktop_log("failed to open data folder: path unavailable"),
+            }
+        }
+        Some(tray_actions::TrayMenuAction::QuitConfirm) => {
+            quit_desktop_process(app_handle);
+        }
+        Some(tray_actions::TrayMenuAction::QuitCancel) => {
+            append_shutdown_log("tray quit submenu: cancelled");
         }
         None => {}
     }
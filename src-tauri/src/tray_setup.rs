@@ -1,28 +1,28 @@
 use tauri::{
-    menu::{Menu, MenuItem, PredefinedMenuItem},
+    menu::{Menu, MenuItem, PredefinedMenuItem, Submenu},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
     AppHandle, Manager,
 };
 
 use crate::{
     append_desktop_log, runtime_paths, shell_locale, tray_actions, tray_labels, tray_menu_handler,
-    window_actions, AutoUpdateCheckState, TrayMenuState, DEFAULT_SHELL_LOCALE, TRAY_ID,
+    window_actions, AutoUpdateCheckState, TrayMenuState, UpdateChannel, UpdateChannelState,
+    DEFAULT_SHELL_LOCALE, TRAY_ID,
 };
 
 pub fn setup_tray(app_handle: &AppHandle) -> Result<(), String> {
-    let locale = shell_locale::resolve_shell_locale(
-        DEFAULT_SHELL_LOCALE,
-        runtime_paths::default_packaged_root_dir(),
-    );
-    let shell_texts = shell_locale::shell_texts_for_locale(locale);
+    let packaged_root_dir = runtime_paths::default_packaged_root_dir();
+    let locale =
+        shell_locale::resolve_shell_locale(DEFAULT_SHELL_LOCALE, packaged_root_dir.clone());
+    let shell_texts = shell_locale::shell_texts_for_locale(&locale, packaged_root_dir.as_deref());
     let main_window_visible = app_handle
         .get_webview_window("main")
         .and_then(|window| window.is_visible().ok())
         .unwrap_or(true);
     let toggle_label = if main_window_visible {
-        shell_texts.tray_hide
+        &shell_texts.tray_hide
     } else {
-        shell_texts.tray_show
+        &shell_texts.tray_show
     };
     let auto_update_check_enabled = app_handle
         .try_state::<AutoUpdateCheckState>()
@@ -34,6 +34,23 @@ pub fn setup_tray(app_handle: &AppHandle) -> Result<(), String> {
         shell_texts.tray_auto_update_check_off
     };
 
+    if !app_handle.manage(UpdateChannelState::new(
+        shell_locale::read_cached_update_channel(
+            runtime_paths::default_packaged_root_dir().as_deref(),
+        )
+        .unwrap_or_default(),
+    )) {
+        append_desktop_log("update channel state already exists, skipping manage");
+    }
+    let update_channel = app_handle
+        .try_state::<UpdateChannelState>()
+        .map(|state| state.current())
+        .unwrap_or_default();
+    let update_channel_label = match update_channel {
+        UpdateChannel::Stable => &shell_texts.tray_update_channel_stable,
+        UpdateChannel::Beta => &shell_texts.tray_update_channel_beta,
+    };
+
     let toggle_item = MenuItem::with_id(
         app_handle,
         tray_actions::TRAY_MENU_TOGGLE_WINDOW,
@@ -51,13 +68,29 @@ pub fn setup_tray(app_handle: &AppHandle) -> Result<(), String> {
     )
     .map_err(|error| format!("Failed to create tray reload menu item: {error}"))?;
     let restart_backend_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_RESTART_BACKEND_CONFIRM,
+        shell_texts.tray_restart_backend_confirm,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray restart confirm menu item: {error}"))?;
+    let restart_backend_cancel_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_RESTART_BACKEND_CANCEL,
+        shell_texts.tray_restart_backend_cancel,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray restart cancel menu item: {error}"))?;
+    let restart_backend_menu = Submenu::with_id_and_items(
         app_handle,
         tray_actions::TRAY_MENU_RESTART_BACKEND,
         shell_texts.tray_restart_backend,
         true,
-        None::<&str>,
+        &[&restart_backend_item, &restart_backend_cancel_item],
     )
-    .map_err(|error| format!("Failed to create tray restart menu item: {error}"))?;
+    .map_err(|error| format!("Failed to create tray restart submenu: {error}"))?;
     let auto_update_check_item = MenuItem::with_id(
         app_handle,
         tray_actions::TRAY_MENU_TOGGLE_AUTO_UPDATE_CHECK,
@@ -66,14 +99,86 @@ pub fn setup_tray(app_handle: &AppHandle) -> Result<(), String> {
         None::<&str>,
     )
     .map_err(|error| format!("Failed to create tray auto update menu item: {error}"))?;
+    let update_channel_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_CYCLE_UPDATE_CHANNEL,
+        update_channel_label,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray update channel menu item: {error}"))?;
+    let check_update_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_CHECK_UPDATE,
+        shell_texts.tray_check_update,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray check update menu item: {error}"))?;
+    let cancel_update_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_CANCEL_UPDATE,
+        shell_texts.tray_cancel_update,
+        false,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray cancel update menu item: {error}"))?;
+    let check_external_update_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_CHECK_EXTERNAL_UPDATE,
+        shell_texts.tray_check_external_update,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray check external update menu item: {error}"))?;
+    let open_logs_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_OPEN_LOGS,
+        shell_texts.tray_open_logs,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray open logs menu item: {error}"))?;
+    let open_log_folder_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_OPEN_LOG_FOLDER,
+        shell_texts.tray_open_log_folder,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray open log folder menu item: {error}"))?;
+    let open_data_folder_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_OPEN_DATA_FOLDER,
+        shell_texts.tray_open_data_folder,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray open data folder menu item: {error}"))?;
     let quit_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_QUIT_CONFIRM,
+        shell_texts.tray_quit_confirm,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray quit confirm menu item: {error}"))?;
+    let quit_cancel_item = MenuItem::with_id(
+        app_handle,
+        tray_actions::TRAY_MENU_QUIT_CANCEL,
+        shell_texts.tray_quit_cancel,
+        true,
+        None::<&str>,
+    )
+    .map_err(|error| format!("Failed to create tray quit cancel menu item: {error}"))?;
+    let quit_menu = Submenu::with_id_and_items(
         app_handle,
         tray_actions::TRAY_MENU_QUIT,
         shell_texts.tray_quit,
         true,
-        None::<&str>,
+        &[&quit_item, &quit_cancel_item],
     )
-    .map_err(|error| format!("Failed to create tray quit menu item: {error}"))?;
+    .map_err(|error| format!("Failed to create tray quit submenu: {error}"))?;
     let separator = PredefinedMenuItem::separator(app_handle)
         .map_err(|error| format!("Failed to create tray separator menu item: {error}"))?;
 
@@ -82,10 +187,17 @@ pub fn setup_tray(app_handle: &AppHandle) -> Result<(), String> {
         &[
             &toggle_item,
             &reload_item,
-            &restart_backend_item,
+            &restart_backend_menu,
             &auto_update_check_item,
+            &check_update_item,
+            &cancel_update_item,
+            &update_channel_item,
+            &check_external_update_item,
+            &open_logs_item,
+            &open_log_folder_item,
+            &open_data_folder_item,
             &separator,
-            &quit_item,
+            &quit_menu,
         ],
     )
     .map_err(|error| format!("Failed to build tray menu: {error}"))?;
@@ -95,6 +207,11 @@ pub fn setup_tray(app_handle: &AppHandle) -> Result<(), String> {
         reload_item: reload_item.clone(),
         restart_backend_item: restart_backend_item.clone(),
         auto_update_check_item: auto_update_check_item.clone(),
+        check_update_item: check_update_item.clone(),
+        cancel_update_item: cancel_update_item.clone(),
+        update_channel_item: update_channel_item.clone(),
+        check_external_update_item: check_external_update_item.clone(),
+        open_logs_item: open_logs_item.clone(),
         quit_item: quit_item.clone(),
     }) {
         append_desktop_log("tray menu state already exists, skipping manage");
@@ -120,6 +237,11 @@ pub fn setup_tray(app_handle: &AppHandle) -> Result<(), String> {
                     DEFAULT_SHELL_LOCALE,
                     append_desktop_log,
                 );
+                tray_labels::refresh_tray_menu_state(
+                    tray.app_handle(),
+                    DEFAULT_SHELL_LOCALE,
+                    append_desktop_log,
+                );
                 if button == MouseButton::Left {
                     window_actions::toggle_main_window(
                         tray.app_handle(),
@@ -138,5 +260,6 @@ pub fn setup_tray(app_handle: &AppHandle) -> Result<(), String> {
         .map_err(|error| format!("Failed to create tray icon: {error}"))?;
 
     tray_labels::update_tray_menu_labels(app_handle, DEFAULT_SHELL_LOCALE, append_desktop_log);
+    tray_labels::refresh_tray_menu_state(app_handle, DEFAULT_SHELL_LOCALE, append_desktop_log);
     Ok(())
 }
@@ -0,0 +1,44 @@
+use tauri::AppHandle;
+use tauri_plugin_updater::{Updater, UpdaterExt};
+use url::Url;
+
+use crate::UpdateChannel;
+
+/// Builds an [`Updater`] whose endpoints honor the given [`UpdateChannel`],
+/// appending `?channel=beta` to each configured endpoint when the beta
+/// channel is selected so a channel-specific `latest.json` can be served.
+pub(crate) fn build_updater_for_channel(
+    app_handle: &AppHandle,
+    channel: UpdateChannel,
+) -> tauri::Result<Updater<tauri::Wry>> {
+    if channel == UpdateChannel::Stable {
+        return app_handle.updater();
+    }
+
+    let beta_endpoints: Vec<Url> = app_handle
+        .config()
+        .plugins
+        .0
+        .get("updater")
+        .and_then(|config| config.get("endpoints"))
+        .and_then(|value| value.as_array())
+        .map(|endpoints| {
+            endpoints
+                .iter()
+                .filter_map(|endpoint| endpoint.as_str())
+                .filter_map(|endpoint| Url::parse(endpoint).ok())
+                .map(|mut url| {
+                    url.query_pairs_mut()
+                        .append_pair("channel", channel.as_str());
+                    url
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if beta_endpoints.is_empty() {
+        return app_handle.updater();
+    }
+
+    app_handle.updater_builder().endpoints(beta_endpoints)?.build()
+}
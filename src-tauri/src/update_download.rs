@@ -0,0 +1,163 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex,
+};
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_updater::Update;
+
+use crate::{
+    append_desktop_log, tray_labels, UpdateDownloadFinishedPayload, UpdateDownloadProgressPayload,
+    UpdateDownloadStartedPayload, UpdateState, UPDATE_DOWNLOAD_FINISHED_EVENT,
+    UPDATE_DOWNLOAD_PROGRESS_EVENT, UPDATE_DOWNLOAD_STARTED_EVENT,
+};
+
+const UPDATE_PROGRESS_THROTTLE: Duration = Duration::from_millis(200);
+
+/// How often the cancellation watcher re-checks [`UpdateState::is_cancel_requested`]
+/// while racing the download future, so "Cancel Update" can abort a download
+/// that has stalled after headers (no further chunks, so the per-chunk
+/// progress callback — the only other place cancellation is checked — never
+/// runs again).
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Outcome of [`download_update_with_progress`]. Cancellation and an
+/// already-in-flight download are both expected, non-error stops (the caller
+/// decides how to log/report them), distinct from a genuine transport
+/// [`Self::Failed`].
+pub(crate) enum UpdateDownloadOutcome {
+    Downloaded(Vec<u8>),
+    AlreadyInProgress,
+    Cancelled,
+    Failed(String),
+}
+
+/// Downloads `update`, emitting a [`UPDATE_DOWNLOAD_STARTED_EVENT`], throttled
+/// [`UPDATE_DOWNLOAD_PROGRESS_EVENT`]s, and a terminal
+/// [`UPDATE_DOWNLOAD_FINISHED_EVENT`], and honoring [`UpdateState`]'s cancel
+/// flag, so the startup auto-update check and the desktop-bridge update
+/// commands share one download path instead of each re-deriving the
+/// progress-throttling/cancellation plumbing. Cancellation races the
+/// download future itself (see the `tokio::select!` below) rather than only
+/// being checked from inside the per-chunk callback, so it still aborts a
+/// download that has stalled after headers with no further chunks arriving.
+pub(crate) async fn download_update_with_progress(
+    app_handle: &AppHandle,
+    update: &Update<tauri::Wry>,
+) -> UpdateDownloadOutcome {
+    let update_state = app_handle.state::<UpdateState>();
+    if !update_state.begin_download() {
+        return UpdateDownloadOutcome::AlreadyInProgress;
+    }
+    tray_labels::set_cancel_update_enabled(app_handle, true, append_desktop_log);
+
+    let downloaded_so_far = Arc::new(AtomicU64::new(0));
+    let last_emit_at = Arc::new(Mutex::new(Instant::now()));
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let started = Arc::new(AtomicBool::new(false));
+
+    let progress_app_handle = app_handle.clone();
+    let progress_downloaded = downloaded_so_far.clone();
+    let progress_last_emit = last_emit_at.clone();
+    let progress_cancelled = cancelled.clone();
+    let progress_started = started.clone();
+    let finish_app_handle = app_handle.clone();
+    let finish_downloaded = downloaded_so_far.clone();
+
+    let download_future = update.download(
+        move |chunk_len, total| {
+            if progress_app_handle
+                .state::<UpdateState>()
+                .is_cancel_requested()
+            {
+                progress_cancelled.store(true, Ordering::Relaxed);
+                return;
+            }
+
+            let downloaded = progress_downloaded.fetch_add(chunk_len as u64, Ordering::Relaxed)
+                + chunk_len as u64;
+
+            if !progress_started.swap(true, Ordering::Relaxed) {
+                let _ = progress_app_handle.emit(
+                    UPDATE_DOWNLOAD_STARTED_EVENT,
+                    UpdateDownloadStartedPayload { total },
+                );
+            }
+
+            let mut last_emit = match progress_last_emit.lock() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            if last_emit.elapsed() < UPDATE_PROGRESS_THROTTLE {
+                return;
+            }
+            *last_emit = Instant::now();
+            drop(last_emit);
+
+            let percent = total
+                .filter(|total| *total > 0)
+                .map(|total| (downloaded as f64 / total as f64) * 100.0);
+
+            let _ = progress_app_handle.emit(
+                UPDATE_DOWNLOAD_PROGRESS_EVENT,
+                UpdateDownloadProgressPayload {
+                    downloaded,
+                    total,
+                    percent,
+                },
+            );
+        },
+        move || {
+            let _ = finish_app_handle.emit(
+                UPDATE_DOWNLOAD_FINISHED_EVENT,
+                UpdateDownloadFinishedPayload {
+                    downloaded: finish_downloaded.load(Ordering::Relaxed),
+                },
+            );
+        },
+    );
+    tokio::pin!(download_future);
+
+    // `update.download()` only gives us per-chunk/on-finish callbacks, so a
+    // connection that goes quiet after headers (no more chunks) never runs
+    // the progress callback again, and the cooperative cancel check inside
+    // it never fires. Race it against a cancellation watcher so dropping
+    // `download_future` here (and, with it, the underlying connection) is
+    // what actually aborts a stalled download, not just a checked flag.
+    let cancel_app_handle = app_handle.clone();
+    let cancel_watcher = async {
+        loop {
+            if cancel_app_handle
+                .state::<UpdateState>()
+                .is_cancel_requested()
+            {
+                return;
+            }
+            tokio::time::sleep(CANCEL_POLL_INTERVAL).await;
+        }
+    };
+
+    let download_result = tokio::select! {
+        result = &mut download_future => Some(result),
+        _ = cancel_watcher => {
+            cancelled.store(true, Ordering::Relaxed);
+            None
+        }
+    };
+
+    app_handle.state::<UpdateState>().finish_download();
+    tray_labels::set_cancel_update_enabled(app_handle, false, append_desktop_log);
+
+    if cancelled.load(Ordering::Relaxed) {
+        return UpdateDownloadOutcome::Cancelled;
+    }
+
+    match download_result {
+        Some(Ok(bytes)) => UpdateDownloadOutcome::Downloaded(bytes),
+        Some(Err(error)) => {
+            UpdateDownloadOutcome::Failed(format!("Failed to download desktop app update: {error}"))
+        }
+        None => UpdateDownloadOutcome::Cancelled,
+    }
+}
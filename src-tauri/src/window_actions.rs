@@ -1,6 +1,6 @@
 use tauri::{AppHandle, Manager};
 
-use crate::{main_window, tray_labels};
+use crate::{log_window, main_window, tray_labels};
 
 pub fn show_main_window<F>(app_handle: &AppHandle, default_shell_locale: &'static str, log: F)
 where
@@ -52,3 +52,17 @@ where
 {
     main_window::reload_main_window(app_handle, log);
 }
+
+pub fn show_log_window<F>(app_handle: &AppHandle, log: F)
+where
+    F: Fn(&str),
+{
+    log_window::show_log_window(app_handle, log);
+}
+
+pub fn hide_log_window<F>(app_handle: &AppHandle, log: F)
+where
+    F: Fn(&str),
+{
+    log_window::hide_log_window(app_handle, log);
+}